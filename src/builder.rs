@@ -0,0 +1,224 @@
+//! Builder for a fully configured TCGdex client
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::client::TCGdex;
+use crate::error::{Error, Result};
+use crate::models::Language;
+use crate::transport::{ReqwestTransport, Transport};
+
+/// Builder for a [`TCGdex`] client, created with [`TCGdex::builder`]
+///
+/// Invalid options are only reported by [`build`](Self::build).
+///
+/// ```
+/// use std::time::Duration;
+/// use tcgdex_sdk::{Language, TCGdex};
+///
+/// let tcgdex = TCGdex::builder()
+///     .language(Language::FR)
+///     .fallback_language(Language::EN)
+///     .timeout(Duration::from_secs(10))
+///     .user_agent_suffix("my-app/1.0")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct TCGdexBuilder {
+    /// API endpoint URL
+    endpoint: String,
+    /// Language of the API responses
+    language: Language,
+    /// Language used when a resource does not exist in the main one
+    fallback_language: Option<Language>,
+    /// Total timeout of a request
+    timeout: Option<Duration>,
+    /// Timeout of the connection phase of a request
+    connect_timeout: Option<Duration>,
+    /// Proxy URL for every request
+    proxy: Option<String>,
+    /// Headers sent with every request, validated when building
+    headers: Vec<(String, String)>,
+    /// Appended to the SDK user agent
+    user_agent_suffix: Option<String>,
+    /// Existing HTTP client to send the requests with
+    client: Option<reqwest::Client>,
+    /// Custom transport to send the requests with
+    transport: Option<Arc<dyn Transport>>,
+    /// Query parameters added to every request
+    default_query: Vec<(String, String)>,
+}
+
+impl TCGdexBuilder {
+    /// Create a builder with the default endpoint and language
+    pub fn new() -> Self {
+        Self {
+            endpoint: TCGdex::DEFAULT_ENDPOINT.to_string(),
+            language: Language::default(),
+            fallback_language: None,
+            timeout: None,
+            connect_timeout: None,
+            proxy: None,
+            headers: Vec::new(),
+            user_agent_suffix: None,
+            client: None,
+            transport: None,
+            default_query: Vec::new(),
+        }
+    }
+
+    /// Set the API endpoint URL
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+
+    /// Set the language of the API responses
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// Set the language used to get a single item that does not exist in the main language
+    pub fn fallback_language(mut self, language: Language) -> Self {
+        self.fallback_language = Some(language);
+        self
+    }
+
+    /// Set the total timeout of a request, including reading the response
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the timeout for establishing a connection
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send every request through the proxy at the given URL
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// Add a header sent with every request
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Append a product identifier to the SDK user agent, e.g. `my-app/1.0`
+    pub fn user_agent_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.user_agent_suffix = Some(suffix.into());
+        self
+    }
+
+    /// Send the requests with an existing HTTP client
+    ///
+    /// The client keeps its own configuration, so it cannot be combined with
+    /// the timeouts, proxy, headers or user agent options.
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Send the requests through a custom transport
+    ///
+    /// Like [`client`](Self::client), it cannot be combined with the HTTP options.
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Add a query parameter to every request, e.g. a tracking or API version parameter
+    pub fn default_query_param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.default_query.push((key.into(), value.into()));
+        self
+    }
+
+    /// Build the client, checking the configuration
+    pub fn build(self) -> Result<TCGdex> {
+        let endpoint = url::Url::parse(&self.endpoint)?;
+        if endpoint.cannot_be_a_base() {
+            return Err(Error::Config(format!(
+                "endpoint is not a valid base URL: {}",
+                self.endpoint
+            )));
+        }
+
+        let transport = self.build_transport()?;
+        let mut tcgdex = TCGdex::with_shared_transport(
+            self.endpoint.trim_end_matches('/'),
+            self.language,
+            transport,
+        );
+        tcgdex.set_fallback_language(self.fallback_language);
+        tcgdex.set_default_query(self.default_query);
+        Ok(tcgdex)
+    }
+
+    // Build the transport from the HTTP options, or use the one given
+    fn build_transport(&self) -> Result<Arc<dyn Transport>> {
+        let has_http_options = self.timeout.is_some()
+            || self.connect_timeout.is_some()
+            || self.proxy.is_some()
+            || !self.headers.is_empty()
+            || self.user_agent_suffix.is_some();
+
+        match (&self.client, &self.transport) {
+            (Some(_), Some(_)) => Err(Error::Config(
+                "a client and a transport cannot be used together".to_string(),
+            )),
+            (Some(_), None) | (None, Some(_)) if has_http_options => Err(Error::Config(
+                "HTTP options cannot be combined with an existing client or transport".to_string(),
+            )),
+            (Some(client), None) => Ok(Arc::new(ReqwestTransport::new(client.clone()))),
+            (None, Some(transport)) => Ok(Arc::clone(transport)),
+            (None, None) => Ok(Arc::new(ReqwestTransport::new(self.build_client()?))),
+        }
+    }
+
+    // Build a reqwest client from the HTTP options
+    fn build_client(&self) -> Result<reqwest::Client> {
+        let mut user_agent = format!("tcgdex-rust-sdk/{}", crate::VERSION);
+        if let Some(suffix) = &self.user_agent_suffix {
+            user_agent.push(' ');
+            user_agent.push_str(suffix);
+        }
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| Error::Config(format!("invalid header name: {}", name)))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|_| Error::Config(format!("invalid value for header {}", name)))?;
+            headers.append(name, value);
+        }
+
+        let mut builder = reqwest::Client::builder()
+            .user_agent(user_agent)
+            .default_headers(headers);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+
+        Ok(builder.build()?)
+    }
+}
+
+impl Default for TCGdexBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,163 @@
+//! In-memory cache of API responses
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::transport::Response;
+
+/// Configuration of the in-memory response cache
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheConfig {
+    /// How long a response stays valid once cached, too large durations like
+    /// [`Duration::MAX`] never expire
+    pub ttl: Duration,
+    /// Maximum number of cached responses, the least recently used one is evicted first
+    pub max_entries: usize,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(60 * 60),
+            max_entries: 1000,
+        }
+    }
+}
+
+/// A cache entry
+#[derive(Debug)]
+struct Entry {
+    /// The cached response
+    response: Response,
+    /// When the entry stops being valid, `None` if it never does
+    expires_at: Option<Instant>,
+    /// Value of the access counter when the entry was last used
+    last_used: u64,
+}
+
+impl Entry {
+    /// Check if the entry is no longer valid at this instant
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// Cache content, guarded by the cache mutex
+#[derive(Debug, Default)]
+struct Entries {
+    /// Entries by full request URL
+    map: HashMap<String, Entry>,
+    /// Access counter used to find the least recently used entry
+    counter: u64,
+}
+
+/// In-memory cache of API responses keyed by full request URL
+///
+/// The URL includes the API endpoint, the language and the query string, so
+/// the same resource requested in two languages is cached twice.
+#[derive(Debug)]
+pub struct MemoryCache {
+    /// Cache configuration
+    config: CacheConfig,
+    /// Cached entries
+    entries: Mutex<Entries>,
+}
+
+impl MemoryCache {
+    /// Create a new empty cache
+    pub fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// Get the cache configuration
+    pub fn config(&self) -> &CacheConfig {
+        &self.config
+    }
+
+    /// Remove the response cached for the given URL, returns whether one was cached
+    pub fn invalidate(&self, url: &str) -> bool {
+        self.lock().map.remove(url).is_some()
+    }
+
+    /// Remove every response whose URL starts with the given prefix
+    pub fn invalidate_prefix(&self, prefix: &str) {
+        self.lock().map.retain(|url, _| !url.starts_with(prefix));
+    }
+
+    /// Remove every cached response
+    pub fn clear(&self) {
+        self.lock().map.clear();
+    }
+
+    /// Get the number of cached responses, including expired ones not yet evicted
+    pub fn len(&self) -> usize {
+        self.lock().map.len()
+    }
+
+    /// Check if the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the response cached for the given URL if it is still valid
+    pub(crate) fn get(&self, url: &str) -> Option<Response> {
+        let mut entries = self.lock();
+        entries.counter += 1;
+        let counter = entries.counter;
+
+        let entry = entries.map.get_mut(url)?;
+        if entry.is_expired(Instant::now()) {
+            entries.map.remove(url);
+            return None;
+        }
+
+        entry.last_used = counter;
+        Some(entry.response.clone())
+    }
+
+    /// Cache a response for the given URL, evicting the least recently used entries if full
+    pub(crate) fn insert(&self, url: &str, response: Response) {
+        if self.config.max_entries == 0 {
+            return;
+        }
+
+        let mut entries = self.lock();
+        entries.counter += 1;
+        let counter = entries.counter;
+
+        if !entries.map.contains_key(url) {
+            let now = Instant::now();
+            entries.map.retain(|_, entry| !entry.is_expired(now));
+
+            while entries.map.len() >= self.config.max_entries {
+                let oldest = entries
+                    .map
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_used)
+                    .map(|(url, _)| url.clone());
+                match oldest {
+                    Some(oldest) => entries.map.remove(&oldest),
+                    None => break,
+                };
+            }
+        }
+
+        entries.map.insert(
+            url.to_string(),
+            Entry {
+                response,
+                expires_at: Instant::now().checked_add(self.config.ttl),
+                last_used: counter,
+            },
+        );
+    }
+
+    // Lock the entries, recovering from a poisoned mutex since entries stay consistent
+    fn lock(&self) -> std::sync::MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
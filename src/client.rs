@@ -1,10 +1,22 @@
 //! The main TCGdex client
 
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::builder::TCGdexBuilder;
+use crate::cache::{CacheConfig, MemoryCache};
+use crate::disk_cache::DiskCache;
 use crate::endpoints::Endpoint;
+use crate::error::Result;
+use crate::http::HttpClient;
 use crate::models::{
-    Card, CardResume, IntList, Language, Serie, SerieResume, Set, SetResume, StringEndpoint,
-    StringList,
+    Card, CardResume, EnergyType, IntList, Language, PokemonType, Serie, SerieResume, Set,
+    SetResume, StringEndpoint, StringList,
 };
+use crate::rate_limit::RateLimit;
+use crate::retry::RetryPolicy;
+use crate::transport::{ReqwestTransport, Transport};
+use crate::vcr::{RecordingTransport, ReplayTransport};
 
 /// TCGdex API client
 pub struct TCGdex {
@@ -12,8 +24,12 @@ pub struct TCGdex {
     endpoint: String,
     /// The language to use for API responses
     pub language: Language,
+    /// Language used when a single item does not exist in the main language
+    fallback_language: Option<Language>,
+    /// Query parameters added to every request
+    default_query: Vec<(String, String)>,
     /// HTTP client for requests
-    client: reqwest::Client,
+    http: HttpClient,
     /// Cards endpoint
     pub card: Endpoint<Card, CardResume>,
     /// Sets endpoint
@@ -31,11 +47,11 @@ pub struct TCGdex {
     /// Regulation marks endpoint
     pub regulation_mark: Endpoint<StringEndpoint, StringList>,
     /// Energy types endpoint
-    pub energy_type: Endpoint<StringEndpoint, StringList>,
+    pub energy_type: Endpoint<StringEndpoint, EnergyType>,
     /// Pokedex IDs endpoint
     pub dex_id: Endpoint<StringEndpoint, IntList>,
     /// Types endpoint
-    pub type_: Endpoint<StringEndpoint, StringList>,
+    pub type_: Endpoint<StringEndpoint, PokemonType>,
     /// Retreats endpoint
     pub retreat: Endpoint<StringEndpoint, IntList>,
     /// Rarities endpoint
@@ -52,22 +68,52 @@ impl TCGdex {
     /// Default API endpoint URL
     pub const DEFAULT_ENDPOINT: &'static str = "https://api.tcgdex.net/v2";
 
+    /// Number of concurrent requests used by the convenience methods fetching several items
+    pub const DEFAULT_CONCURRENCY: usize = 8;
+
     /// Create a new TCGdex client with the default endpoint and the specified language
     pub fn new(language: Language) -> Self {
         Self::with_endpoint(Self::DEFAULT_ENDPOINT, language)
     }
 
+    /// Create a builder to configure the HTTP client, fallback language and more
+    pub fn builder() -> TCGdexBuilder {
+        TCGdexBuilder::new()
+    }
+
     /// Create a new TCGdex client with the specified endpoint and language
+    ///
+    /// Use [`builder`](Self::builder) to get an error when the HTTP client cannot be built.
     pub fn with_endpoint(endpoint: &str, language: Language) -> Self {
         let client = reqwest::Client::builder()
             .user_agent(format!("tcgdex-rust-sdk/{}", crate::VERSION))
             .build()
             .unwrap_or_default();
 
+        Self::with_transport(endpoint, language, ReqwestTransport::new(client))
+    }
+
+    /// Create a new TCGdex client sending its requests through the given transport
+    pub fn with_transport(
+        endpoint: &str,
+        language: Language,
+        transport: impl Transport + 'static,
+    ) -> Self {
+        Self::with_shared_transport(endpoint, language, Arc::new(transport))
+    }
+
+    // Create a new TCGdex client sending its requests through a shared transport
+    pub(crate) fn with_shared_transport(
+        endpoint: &str,
+        language: Language,
+        transport: Arc<dyn Transport>,
+    ) -> Self {
         let mut sdk = Self {
             endpoint: endpoint.to_string(),
             language,
-            client,
+            fallback_language: None,
+            default_query: Vec::new(),
+            http: HttpClient::new(transport),
             card: Endpoint::default(),
             set: Endpoint::default(),
             serie: Endpoint::default(),
@@ -110,9 +156,130 @@ impl TCGdex {
         self
     }
 
-    /// Get the HTTP client used for requests
-    pub fn client(&self) -> &reqwest::Client {
-        &self.client
+    /// Get the language used when a single item does not exist in the main language
+    pub fn fallback_language(&self) -> Option<Language> {
+        self.fallback_language
+    }
+
+    /// Set the language used when a single item does not exist in the main language
+    pub fn set_fallback_language(&mut self, language: Option<Language>) -> &mut Self {
+        self.fallback_language = language;
+        self.update_endpoints();
+        self
+    }
+
+    /// Get the query parameters added to every request
+    pub fn default_query(&self) -> &[(String, String)] {
+        &self.default_query
+    }
+
+    /// Set the query parameters added to every request
+    pub fn set_default_query(&mut self, params: Vec<(String, String)>) -> &mut Self {
+        self.default_query = params;
+        self.update_endpoints();
+        self
+    }
+
+    /// Get the HTTP client used for requests, `None` with a transport not based on `reqwest`
+    #[deprecated(note = "requests go through `transport()`, which may not use `reqwest`")]
+    pub fn client(&self) -> Option<&reqwest::Client> {
+        self.transport().reqwest_client()
+    }
+
+    /// Get the transport used to send requests
+    pub fn transport(&self) -> &Arc<dyn Transport> {
+        self.http.transport()
+    }
+
+    /// Set the transport used to send requests
+    pub fn set_transport(&mut self, transport: impl Transport + 'static) -> &mut Self {
+        self.http.set_transport(Arc::new(transport));
+        self.update_endpoints();
+        self
+    }
+
+    /// Record every request and response into a cassette file
+    ///
+    /// Requests keep going through the current transport. An existing cassette
+    /// at this path is overwritten.
+    pub fn record(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        let inner = Arc::clone(self.http.transport());
+        self.set_transport(RecordingTransport::new(inner, path))
+    }
+
+    /// Serve every request from a cassette file recorded with [`record`](Self::record)
+    ///
+    /// Requests missing from the cassette fail instead of reaching the API.
+    pub fn replay(&mut self, path: impl AsRef<Path>) -> Result<&mut Self> {
+        let transport = ReplayTransport::new(path)?;
+        Ok(self.set_transport(transport))
+    }
+
+    /// Get the retry policy applied to every request
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        self.http.retry_policy()
+    }
+
+    /// Set the retry policy applied to every request
+    pub fn set_retry_policy(&mut self, retry: RetryPolicy) -> &mut Self {
+        self.http.set_retry_policy(retry);
+        self.update_endpoints();
+        self
+    }
+
+    /// Get the rate limit shared by every endpoint
+    pub fn rate_limit(&self) -> &RateLimit {
+        self.http.rate_limit()
+    }
+
+    /// Set the rate limit shared by every endpoint and image download
+    pub fn set_rate_limit(&mut self, rate_limit: RateLimit) -> &mut Self {
+        self.http.set_rate_limit(rate_limit);
+        self.update_endpoints();
+        self
+    }
+
+    /// Get the in-memory response cache, if enabled
+    pub fn cache(&self) -> Option<&MemoryCache> {
+        self.http.cache()
+    }
+
+    /// Enable the in-memory response cache with the given configuration, or disable it with `None`
+    ///
+    /// Any previously cached response is dropped.
+    pub fn set_cache(&mut self, config: Option<CacheConfig>) -> &mut Self {
+        self.http.set_cache(config);
+        self.update_endpoints();
+        self
+    }
+
+    /// Remove every response from the in-memory cache
+    ///
+    /// The cache is shared, so a client behind an `Arc` can clear it too.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = self.http.cache() {
+            cache.clear();
+        }
+    }
+
+    /// Get the persistent disk cache, if enabled
+    pub fn disk_cache(&self) -> Option<&DiskCache> {
+        self.http.disk_cache()
+    }
+
+    /// Enable the persistent disk cache, or disable it with `None`
+    ///
+    /// Responses stored on disk are revalidated with conditional requests, so
+    /// they are only downloaded again when the API data changed.
+    pub fn set_disk_cache(&mut self, disk_cache: Option<DiskCache>) -> &mut Self {
+        self.http.set_disk_cache(disk_cache);
+        self.update_endpoints();
+        self
+    }
+
+    // Get the HTTP layer shared by the endpoints
+    pub(crate) fn http(&self) -> &HttpClient {
+        &self.http
     }
 
     // Initialize or update all endpoints
@@ -0,0 +1,169 @@
+//! Persistent on-disk cache of API responses
+
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use bytes::Bytes;
+use reqwest::header::{HeaderMap, HeaderName, ETAG, LAST_MODIFIED};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+
+/// Validators and metadata stored next to a cached response body
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct DiskEntry {
+    /// Full request URL, used to detect file name collisions
+    pub url: String,
+    /// Value of the `ETag` response header
+    pub etag: Option<String>,
+    /// Value of the `Last-Modified` response header
+    #[serde(rename = "lastModified")]
+    pub last_modified: Option<String>,
+}
+
+impl DiskEntry {
+    /// Build an entry from the headers of a response
+    pub(crate) fn from_headers(url: &str, headers: &HeaderMap) -> Self {
+        let header = |name: HeaderName| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+
+        Self {
+            url: url.to_string(),
+            etag: header(ETAG),
+            last_modified: header(LAST_MODIFIED),
+        }
+    }
+
+    /// Check if the entry can be revalidated with a conditional request
+    pub(crate) fn has_validators(&self) -> bool {
+        self.etag.is_some() || self.last_modified.is_some()
+    }
+}
+
+/// Persistent HTTP cache storing API responses in a directory
+///
+/// Responses are kept across restarts along with their `ETag` and `Last-Modified`
+/// validators. Cached responses are always revalidated with the API using
+/// `If-None-Match`/`If-Modified-Since`, so an unchanged resource costs a
+/// `304 Not Modified` instead of a full download.
+///
+/// Failing to read or write the cache never fails a request.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    /// Directory holding the cached responses
+    dir: PathBuf,
+}
+
+impl DiskCache {
+    /// Open a disk cache in the given directory, creating it if needed
+    pub fn new(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// Get the directory holding the cached responses
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Remove the response cached for the given URL, returns whether one was cached
+    pub fn invalidate(&self, url: &str) -> Result<bool> {
+        let (meta, body) = self.paths(url);
+        let removed = remove_file(&meta)?;
+        remove_file(&body)?;
+        Ok(removed)
+    }
+
+    /// Remove every cached response
+    ///
+    /// Only the files written by the cache are removed, other files of the
+    /// directory are left untouched.
+    pub fn clear(&self) -> Result<()> {
+        for entry in std::fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            if entry.file_name().to_str().is_some_and(is_cache_file) {
+                remove_file(&entry.path())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Load the entry and body cached for the given URL
+    pub(crate) async fn load(&self, url: &str) -> Option<(DiskEntry, Bytes)> {
+        let (meta, body) = self.paths(url);
+        let entry: DiskEntry = serde_json::from_slice(&tokio::fs::read(meta).await.ok()?).ok()?;
+        if entry.url != url {
+            return None;
+        }
+
+        let body = tokio::fs::read(body).await.ok()?;
+        Some((entry, Bytes::from(body)))
+    }
+
+    /// Store the entry and body for the given URL
+    pub(crate) async fn store(&self, entry: &DiskEntry, body: &[u8]) -> Result<()> {
+        let (meta, body_path) = self.paths(&entry.url);
+
+        // Write to temporary files first so readers never see a partial entry
+        let suffix = fastrand::u64(..);
+        let meta_tmp = meta.with_extension(format!("json.{:x}.tmp", suffix));
+        let body_tmp = body_path.with_extension(format!("body.{:x}.tmp", suffix));
+        tokio::fs::write(&body_tmp, body).await?;
+        tokio::fs::write(&meta_tmp, serde_json::to_vec(entry)?).await?;
+        tokio::fs::rename(body_tmp, body_path).await?;
+        tokio::fs::rename(meta_tmp, meta).await?;
+        Ok(())
+    }
+
+    // Get the metadata and body file paths for a URL
+    fn paths(&self, url: &str) -> (PathBuf, PathBuf) {
+        let name = format!("{:016x}", fnv1a(url.as_bytes()));
+        (
+            self.dir.join(format!("{}.json", name)),
+            self.dir.join(format!("{}.body", name)),
+        )
+    }
+}
+
+/// Remove a file, returns whether it existed
+fn remove_file(path: &Path) -> Result<bool> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(true),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(false),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Check if a file name is one written by the cache: a hash followed by
+/// `.json` or `.body`, or a temporary file of these
+fn is_cache_file(name: &str) -> bool {
+    let is_hex = |text: &str, len: Option<usize>| {
+        !text.is_empty()
+            && len.is_none_or(|len| text.len() == len)
+            && text.bytes().all(|byte| byte.is_ascii_hexdigit())
+    };
+    let mut parts = name.split('.');
+    let (Some(hash), Some(kind)) = (parts.next(), parts.next()) else {
+        return false;
+    };
+    if !is_hex(hash, Some(16)) || !["json", "body"].contains(&kind) {
+        return false;
+    }
+    match (parts.next(), parts.next(), parts.next()) {
+        (None, _, _) => true,
+        (Some(suffix), Some("tmp"), None) => is_hex(suffix, None),
+        _ => false,
+    }
+}
+
+/// 64-bit FNV-1a hash, stable across builds and platforms
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
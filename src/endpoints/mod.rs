@@ -1,57 +1,120 @@
 //! Endpoint handling for the TCGdex API
 
+mod stream;
+
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 use async_trait::async_trait;
+use futures::stream::StreamExt;
 use serde::de::DeserializeOwned;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::http::HttpClient;
+use crate::models::Language;
 use crate::query::Query;
+use crate::single_flight::SingleFlight;
+use crate::transport::Response;
 use crate::TCGdex;
 
+pub use stream::StreamConfig;
+
 /// A trait for models that can be fetched from the API
 #[async_trait]
 pub trait Fetchable: Sized + DeserializeOwned + Debug {
-    /// Fetch a model by ID
+    /// Fetch a model by ID with a bare HTTP client
+    ///
+    /// Skips the transport, caches, rate limit and retries of the SDK.
+    #[deprecated(note = "use `fetch_with`, which goes through the configured `TCGdex` client")]
     async fn fetch(client: &reqwest::Client, url: &str) -> Result<Self> {
         let response = client.get(url).send().await?.error_for_status()?;
         let data = response.json().await?;
         Ok(data)
     }
+
+    /// Fetch a model by ID through the transport, caches and retries of the client
+    ///
+    /// Like [`Endpoint::get`], the default query parameters of the client are
+    /// added and a missing model is fetched in the fallback language, if any.
+    async fn fetch_with(tcgdex: &TCGdex, url: &str) -> Result<Self> {
+        let result = fetch_item(tcgdex, url).await;
+        let fallback = match &result {
+            Err(Error::NotFound { .. }) => fallback_url(tcgdex, url),
+            _ => None,
+        };
+
+        match fallback {
+            Some(fallback) => fetch_item(tcgdex, &fallback).await,
+            None => result,
+        }
+    }
 }
 
 /// A trait for models that can be listed from the API
 #[async_trait]
 pub trait Listable: Sized + DeserializeOwned + Debug {
-    /// Fetch a list of models
+    /// Key identifying an item when merging the lists of several requests,
+    /// `None` to keep every item
+    fn merge_key(&self) -> Option<String> {
+        None
+    }
+
+    /// Fetch a list of models with a bare HTTP client
+    ///
+    /// Skips the transport, caches, rate limit and retries of the SDK.
+    #[deprecated(note = "use `fetch_list_with`, which goes through the configured `TCGdex` client")]
     async fn fetch_list(client: &reqwest::Client, url: &str) -> Result<Vec<Self>> {
         let response = client.get(url).send().await?.error_for_status()?;
         let data = response.json().await?;
         Ok(data)
     }
+
+    /// Fetch a list of models through the transport, caches and retries of the client
+    ///
+    /// Like [`Endpoint::list`], the default query parameters of the client are added.
+    async fn fetch_list_with(tcgdex: &TCGdex, url: &str) -> Result<Vec<Self>> {
+        let path = url.split('?').next().unwrap_or_default();
+        let (_, resource) = path.rsplit_once('/').unwrap_or(("", path));
+        let url = with_query(url.to_string(), tcgdex.default_query());
+        let response = tcgdex.http().get(&url).await?;
+        response.error_for_status(resource, "")?.json(&url)
+    }
 }
 
 /// Generic endpoint for TCGdex API resources
 pub struct Endpoint<Item, List> {
     base_url: String,
+    fallback_base_url: Option<String>,
     path: String,
-    client: reqwest::Client,
+    language: Language,
+    default_query: Vec<(String, String)>,
+    http: HttpClient,
+    items_in_flight: Arc<SingleFlight<Item>>,
+    lists_in_flight: Arc<SingleFlight<Vec<List>>>,
     _item: PhantomData<Item>,
     _list: PhantomData<List>,
 }
 
 impl<Item, List> Endpoint<Item, List>
 where
-    Item: Fetchable + Send + Sync,
-    List: Listable + Send + Sync,
+    Item: Fetchable + Clone + Send + Sync + 'static,
+    List: Listable + Clone + Send + Sync + 'static,
 {
     /// Create a new endpoint with the given base URL and path
     pub fn new(sdk: &TCGdex, path: impl Into<String>) -> Self {
         Self {
             base_url: format!("{}/{}", sdk.get_endpoint(), sdk.language),
+            fallback_base_url: sdk
+                .fallback_language()
+                .filter(|language| *language != sdk.language)
+                .map(|language| format!("{}/{}", sdk.get_endpoint(), language)),
             path: path.into(),
-            client: sdk.client().clone(),
+            language: sdk.language,
+            default_query: sdk.default_query().to_vec(),
+            http: sdk.http().clone(),
+            items_in_flight: Arc::new(SingleFlight::new()),
+            lists_in_flight: Arc::new(SingleFlight::new()),
             _item: PhantomData,
             _list: PhantomData,
         }
@@ -63,97 +126,251 @@ where
         self
     }
 
+    /// Get the language of the responses of this endpoint
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
     /// Get a single item by ID
+    ///
+    /// When the item does not exist and a fallback language is set, it is
+    /// fetched in the fallback language instead. Concurrent calls for the same
+    /// item share a single request and all get its result.
     pub async fn get(&self, id: &str) -> Result<Item> {
-        let url = format!("{}/{}/{}", self.base_url, self.path, id.replace(' ', "%20"));
+        let endpoint = self.clone();
+        let id = id.to_string();
 
-        // Print debug info in debug builds
-        #[cfg(debug_assertions)]
-        println!("[DEBUG] Fetching get URL: {}", url);
+        self.items_in_flight
+            .run(&self.item_url(&id), move || async move {
+                let result = endpoint.get_from(&endpoint.base_url, &id).await;
 
-        let response = self.client.get(&url).send().await?;
-
-        #[cfg(debug_assertions)]
-        {
-            let status = response.status();
-            println!("[DEBUG] Response status: {}", status);
-
-            if !status.is_success() {
-                let text = response.text().await?;
-                println!("[DEBUG] Error response body: {}", text);
-                return Err(crate::error::Error::NoData);
-            }
-        }
+                match (&result, &endpoint.fallback_base_url) {
+                    (Err(Error::NotFound { .. }), Some(fallback)) => {
+                        endpoint.get_from(fallback, &id).await
+                    }
+                    _ => result,
+                }
+            })
+            .await
+    }
 
-        let response = response.error_for_status()?;
-        let data = response.json().await?;
-        Ok(data)
+    /// Get several items by ID, with at most `concurrency` requests at a time
+    ///
+    /// Results are in the same order as the IDs, so a missing item does not
+    /// prevent getting the others.
+    pub async fn get_many<I>(&self, ids: I, concurrency: usize) -> Vec<Result<Item>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        futures::stream::iter(ids)
+            .map(|id| async move { self.get(id.as_ref()).await })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
     }
 
     /// List all items, optionally filtered by a query
+    ///
+    /// A query with alternatives the API cannot combine is sent as several
+    /// requests, whose results are merged in order without duplicates. Such a
+    /// query cannot be sorted or paginated, see [`Query::build_all`].
     pub async fn list(&self, query: Option<&Query>) -> Result<Vec<List>> {
-        let mut url = format!("{}/{}", self.base_url, self.path);
+        let Some(query) = query.filter(|query| query.needs_several_requests()) else {
+            return self.list_from(&self.list_url(query)).await;
+        };
 
-        if let Some(q) = query {
-            url.push_str(&q.build());
-        }
+        let urls: Vec<String> = query
+            .build_all()?
+            .iter()
+            .map(|query_string| self.list_url_from(query_string))
+            .collect();
+        let lists =
+            futures::future::try_join_all(urls.iter().map(|url| self.list_from(url))).await?;
 
-        // Print debug info in debug builds
-        #[cfg(debug_assertions)]
-        println!("[DEBUG] Fetching list URL: {}", url);
+        Ok(merge_lists(lists))
+    }
 
-        let response = self.client.get(&url).send().await?;
+    /// Remove a single item from the response cache, returns whether it was cached
+    ///
+    /// The item is also removed in the fallback language, if any.
+    pub fn invalidate(&self, id: &str) -> bool {
+        let Some(cache) = self.http.cache() else {
+            return false;
+        };
 
-        #[cfg(debug_assertions)]
-        {
-            let status = response.status();
-            println!("[DEBUG] Response status: {}", status);
+        let fallback = self
+            .fallback_base_url
+            .as_ref()
+            .is_some_and(|base_url| cache.invalidate(&self.item_url_from(base_url, id)));
+        cache.invalidate(&self.item_url(id)) || fallback
+    }
 
-            if !status.is_success() {
-                let text = response.text().await?;
-                println!("[DEBUG] Error response body: {}", text);
-                return Err(crate::error::Error::NoData);
-            }
-        }
+    /// Remove a list from the response cache, returns whether it was cached
+    pub fn invalidate_list(&self, query: Option<&Query>) -> bool {
+        self.http
+            .cache()
+            .is_some_and(|cache| cache.invalidate(&self.list_url(query)))
+    }
 
-        let response = response.error_for_status()?;
-
-        // For debugging, check the response content
-        #[cfg(debug_assertions)]
-        {
-            let body = response.text().await?;
-            println!("[DEBUG] Response body: {}", body);
-
-            // Re-parse the saved body
-            use serde_json::from_str;
-            match from_str(&body) {
-                Ok(data) => return Ok(data),
-                Err(e) => {
-                    println!("[DEBUG] JSON parsing error: {}", e);
-                    return Err(crate::error::Error::Serialization(e));
-                }
+    /// Remove every item and list of this endpoint from the response cache
+    pub fn invalidate_all(&self) {
+        if let Some(cache) = self.http.cache() {
+            for base_url in std::iter::once(&self.base_url).chain(&self.fallback_base_url) {
+                cache.invalidate_prefix(&format!("{}/{}", base_url, self.path));
             }
         }
+    }
 
-        // In release mode, just parse directly
-        #[cfg(not(debug_assertions))]
-        {
-            let data = response.json().await?;
-            Ok(data)
+    // Get a single item under the given base URL
+    async fn get_from(&self, base_url: &str, id: &str) -> Result<Item> {
+        let url = self.item_url_from(base_url, id);
+        let response = self.request(&url).await?;
+        response.error_for_status(&self.path, id)?.json(&url)
+    }
+
+    // List the items at the given URL, sharing the request with concurrent identical calls
+    async fn list_from(&self, url: &str) -> Result<Vec<List>> {
+        let endpoint = self.clone();
+        let owned_url = url.to_string();
+
+        self.lists_in_flight
+            .run(url, move || async move {
+                let response = endpoint.request(&owned_url).await?;
+                response
+                    .error_for_status(&endpoint.path, "")?
+                    .json(&owned_url)
+            })
+            .await
+    }
+
+    // Send a request within a tracing span when the `tracing` feature is enabled
+    async fn request(&self, url: &str) -> Result<Response> {
+        let request = self.http.get(url);
+
+        #[cfg(feature = "tracing")]
+        let request = tracing::Instrument::instrument(
+            request,
+            tracing::debug_span!(
+                "tcgdex_request",
+                url,
+                language = %self.language,
+                path = %self.path
+            ),
+        );
+
+        request.await
+    }
+
+    // Build the URL of a single item
+    fn item_url(&self, id: &str) -> String {
+        self.item_url_from(&self.base_url, id)
+    }
+
+    // Build the URL of a single item under the given base URL
+    fn item_url_from(&self, base_url: &str, id: &str) -> String {
+        let url = format!("{}/{}/{}", base_url, self.path, id.replace(' ', "%20"));
+        self.with_default_query(url)
+    }
+
+    // Build the URL of a list, including the query string
+    fn list_url(&self, query: Option<&Query>) -> String {
+        self.list_url_from(&query.map(Query::build).unwrap_or_default())
+    }
+
+    // Build the URL of a list from a query string starting with `?`, or empty
+    fn list_url_from(&self, query_string: &str) -> String {
+        let url = format!("{}/{}{}", self.base_url, self.path, query_string);
+        self.with_default_query(url)
+    }
+
+    // Append the default query parameters to a URL
+    fn with_default_query(&self, url: String) -> String {
+        with_query(url, &self.default_query)
+    }
+}
+
+// Append query parameters to a URL
+fn with_query(mut url: String, params: &[(String, String)]) -> String {
+    if params.is_empty() {
+        return url;
+    }
+
+    let params = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(params)
+        .finish();
+    url.push(if url.contains('?') { '&' } else { '?' });
+    url.push_str(&params);
+    url
+}
+
+// Fetch a single model at an item URL, with the default query parameters of the client
+async fn fetch_item<T: DeserializeOwned>(tcgdex: &TCGdex, url: &str) -> Result<T> {
+    let (resource, id) = resource_of(url);
+    let url = with_query(url.to_string(), tcgdex.default_query());
+    let response = tcgdex.http().get(&url).await?;
+    response.error_for_status(resource, id)?.json(&url)
+}
+
+// Build the URL of the same item in the fallback language of the client, if any
+fn fallback_url(tcgdex: &TCGdex, url: &str) -> Option<String> {
+    let fallback = tcgdex
+        .fallback_language()
+        .filter(|language| *language != tcgdex.language)?;
+    let prefix = format!("{}/{}/", tcgdex.get_endpoint(), tcgdex.language);
+    let path = url.strip_prefix(&prefix)?;
+    Some(format!("{}/{}/{}", tcgdex.get_endpoint(), fallback, path))
+}
+
+// Concatenate lists, dropping items whose merge key was already seen
+fn merge_lists<List: Listable>(lists: Vec<Vec<List>>) -> Vec<List> {
+    let mut seen = std::collections::HashSet::new();
+    lists
+        .into_iter()
+        .flatten()
+        .filter(|item| item.merge_key().is_none_or(|key| seen.insert(key)))
+        .collect()
+}
+
+// Split an item URL into the resource kind and the ID, e.g. `cards` and `base1-4`
+fn resource_of(url: &str) -> (&str, &str) {
+    let (path, id) = url.rsplit_once('/').unwrap_or(("", url));
+    let (_, resource) = path.rsplit_once('/').unwrap_or(("", path));
+    (resource, id)
+}
+
+impl<Item, List> Clone for Endpoint<Item, List> {
+    fn clone(&self) -> Self {
+        Self {
+            base_url: self.base_url.clone(),
+            fallback_base_url: self.fallback_base_url.clone(),
+            path: self.path.clone(),
+            language: self.language,
+            default_query: self.default_query.clone(),
+            http: self.http.clone(),
+            items_in_flight: Arc::clone(&self.items_in_flight),
+            lists_in_flight: Arc::clone(&self.lists_in_flight),
+            _item: PhantomData,
+            _list: PhantomData,
         }
     }
 }
 
 impl<Item, List> Default for Endpoint<Item, List>
 where
-    Item: Fetchable + Send + Sync,
-    List: Listable + Send + Sync,
+    Item: Fetchable + Clone + Send + Sync + 'static,
+    List: Listable + Clone + Send + Sync + 'static,
 {
     fn default() -> Self {
         Self {
             base_url: String::new(),
+            fallback_base_url: None,
             path: String::new(),
-            client: reqwest::Client::default(),
+            language: Language::default(),
+            default_query: Vec::new(),
+            http: HttpClient::default(),
+            items_in_flight: Arc::new(SingleFlight::new()),
+            lists_in_flight: Arc::new(SingleFlight::new()),
             _item: PhantomData,
             _list: PhantomData,
         }
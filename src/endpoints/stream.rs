@@ -0,0 +1,178 @@
+//! Lazy iteration over every page of a list endpoint
+
+use std::collections::HashSet;
+
+use futures::future::{self, FutureExt, RemoteHandle};
+use futures::stream::{self, Stream, StreamExt};
+
+use super::{Endpoint, Fetchable, Listable};
+use crate::error::Result;
+use crate::query::Query;
+
+/// Configuration of [`Endpoint::stream_with`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamConfig {
+    /// Number of items requested per page
+    pub page_size: u32,
+    /// Fetch the next page in the background while the current one is consumed
+    pub prefetch: bool,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        Self {
+            page_size: 100,
+            prefetch: true,
+        }
+    }
+}
+
+/// A page being fetched in the background, cancelled when dropped
+type Prefetched<List> = RemoteHandle<Result<Vec<List>>>;
+
+/// State of a paginated stream between two pages
+struct Pages<Item, List> {
+    /// Endpoint sending the requests
+    endpoint: Endpoint<Item, List>,
+    /// Query string of the filters, without pagination
+    query_string: String,
+    /// Number of items per page
+    page_size: u32,
+    /// Whether to prefetch the next page
+    prefetch: bool,
+    /// Next page to yield, starting at 1
+    page: u32,
+    /// Next page, if it is already being fetched
+    next: Option<Prefetched<List>>,
+}
+
+impl<Item, List> Endpoint<Item, List>
+where
+    Item: Fetchable + Clone + Send + Sync + 'static,
+    List: Listable + Clone + Send + Sync + 'static,
+{
+    /// Stream every item matching the query, fetching pages lazily
+    ///
+    /// Uses the default [`StreamConfig`], see [`stream_with`](Self::stream_with).
+    pub fn stream(&self, query: Option<&Query>) -> impl Stream<Item = Result<List>> + Send {
+        self.stream_with(query, StreamConfig::default())
+    }
+
+    /// Stream every item matching the query, fetching one page at a time
+    ///
+    /// A page is only requested once the previous one was consumed, or while
+    /// it is consumed with `prefetch`. The stream ends after an empty or short
+    /// page, or after the first error. Pagination parameters of the query are
+    /// ignored, pages follow `config`. Alternatives needing several requests are
+    /// streamed one after the other, without duplicates, and fail if the query
+    /// is sorted, see [`Query::build_all`].
+    pub fn stream_with(
+        &self,
+        query: Option<&Query>,
+        config: StreamConfig,
+    ) -> impl Stream<Item = Result<List>> + Send {
+        let query = query.map(Query::without_pagination);
+        let (query_strings, error) = match query.as_ref().map(Query::build_all).transpose() {
+            Ok(query_strings) => (query_strings.unwrap_or_else(|| vec![String::new()]), None),
+            Err(err) => (Vec::new(), Some(Err(err))),
+        };
+        let merge = query_strings.len() > 1;
+
+        // Alternatives needing several requests are streamed one after the other
+        let endpoint = self.clone();
+        let mut seen = HashSet::new();
+        stream::iter(error)
+            .chain(stream::iter(query_strings).flat_map(move |query_string| {
+                Pages::stream(endpoint.clone(), query_string, &config)
+            }))
+            .scan(false, |failed, item| {
+                // Stop after reporting the error, even with alternatives left
+                let item = (!*failed).then_some(item);
+                *failed |= matches!(item, Some(Err(_)));
+                future::ready(item)
+            })
+            .filter(move |item| {
+                let keep = match item {
+                    Ok(item) if merge => item.merge_key().is_none_or(|key| seen.insert(key)),
+                    _ => true,
+                };
+                future::ready(keep)
+            })
+    }
+}
+
+impl<Item, List> Pages<Item, List>
+where
+    Item: Fetchable + Clone + Send + Sync + 'static,
+    List: Listable + Clone + Send + Sync + 'static,
+{
+    /// Stream the items of every page of a query string
+    fn stream(
+        endpoint: Endpoint<Item, List>,
+        query_string: String,
+        config: &StreamConfig,
+    ) -> impl Stream<Item = Result<List>> + Send {
+        let pages = Self {
+            endpoint,
+            query_string,
+            page_size: config.page_size.max(1),
+            prefetch: config.prefetch,
+            page: 1,
+            next: None,
+        };
+
+        stream::unfold(Some(pages), |pages| async move {
+            let mut pages = pages?;
+            let result = match pages.next.take() {
+                Some(prefetched) => prefetched.await,
+                None => pages.fetch(pages.page).await,
+            };
+
+            let items = match result {
+                Ok(items) => items,
+                // Stop after reporting the error
+                Err(err) => return Some((vec![Err(err)], None)),
+            };
+
+            if items.len() < pages.page_size as usize {
+                return Some((items.into_iter().map(Ok).collect(), None));
+            }
+
+            pages.page += 1;
+            if pages.prefetch {
+                pages.next = Some(pages.spawn_fetch(pages.page));
+            }
+            Some((items.into_iter().map(Ok).collect(), Some(pages)))
+        })
+        .flat_map(stream::iter)
+    }
+
+    /// Fetch a page of items
+    async fn fetch(&self, page: u32) -> Result<Vec<List>> {
+        self.endpoint.list_from(&self.page_url(page)).await
+    }
+
+    /// Start fetching a page of items in the background
+    fn spawn_fetch(&self, page: u32) -> Prefetched<List> {
+        let endpoint = self.endpoint.clone();
+        let url = self.page_url(page);
+
+        let (task, handle) = async move { endpoint.list_from(&url).await }.remote_handle();
+        tokio::spawn(task);
+        handle
+    }
+
+    /// Build the URL of a page, keeping the filters of the query
+    fn page_url(&self, page: u32) -> String {
+        let mut pagination = Query::new();
+        pagination.paginate(page, self.page_size);
+        let pagination = pagination.build();
+
+        let query_string = if self.query_string.is_empty() {
+            pagination
+        } else {
+            format!("{}&{}", self.query_string, &pagination[1..])
+        };
+        self.endpoint.list_url_from(&query_string)
+    }
+}
@@ -1,5 +1,7 @@
 //! Error types for the TCGdex SDK
 
+use std::time::Duration;
+
 use thiserror::Error;
 
 /// A result type for TCGdex operations
@@ -12,19 +14,136 @@ pub enum Error {
     #[error("HTTP error: {0}")]
     Http(#[from] reqwest::Error),
 
+    /// A request failed in the HTTP client, with the error message
+    ///
+    /// Endpoint requests report HTTP client errors this way, since their
+    /// result is shared by every concurrent caller and [`Error::Http`] cannot be copied.
+    #[error("HTTP error: {0}")]
+    Transport(String),
+
     /// An error occurred while parsing a URL
     #[error("URL error: {0}")]
     Url(#[from] url::ParseError),
 
-    /// An error occurred with serialization or deserialization
+    /// An error occurred while reading or writing a file
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// An error occurred with serialization or deserialization outside of API responses
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
-    /// The API returned no data
-    #[error("No data returned from API")]
-    NoData,
+    /// The requested resource does not exist
+    #[error("{resource} not found: {id}")]
+    NotFound {
+        /// Kind of resource, e.g. `cards`
+        resource: String,
+        /// ID of the resource, empty for lists
+        id: String,
+    },
+
+    /// The requested image does not exist
+    #[error("Image not found: {url}")]
+    ImageNotFound {
+        /// URL of the image
+        url: String,
+    },
+
+    /// The API kept refusing requests because of its rate limit
+    #[error("Rate limited by the API")]
+    RateLimited {
+        /// How long the API asked to wait before retrying, if it said so
+        retry_after: Option<Duration>,
+    },
+
+    /// The API failed with a server error status
+    #[error("API server error {status}: {body}")]
+    Server {
+        /// Response status
+        status: u16,
+        /// Response body
+        body: String,
+    },
+
+    /// The API rejected the request with a client error status
+    #[error("API returned status {status}: {body}")]
+    Client {
+        /// Response status
+        status: u16,
+        /// Response body
+        body: String,
+    },
+
+    /// A response could not be decoded into the expected model
+    #[error("Failed to decode response from {url}: {source}")]
+    Decode {
+        /// Requested URL
+        url: String,
+        /// Beginning of the response body
+        body_snippet: String,
+        /// Underlying decoding error
+        source: serde_json::Error,
+    },
+
+    /// A request was not found in the cassette being replayed
+    #[error("No recorded interaction for {0}")]
+    NotRecorded(String),
+
+    /// The client configuration is invalid
+    #[error("Invalid configuration: {0}")]
+    Config(String),
+
+    /// A query string could not be parsed
+    #[error("Invalid query: {0}")]
+    InvalidQuery(String),
 
     /// A generic error occurred
     #[error("{0}")]
     Other(String),
 }
+
+impl Error {
+    /// Copy an error to report it to every waiter of a shared request
+    ///
+    /// Errors of other crates that cannot be copied keep their message, as
+    /// [`Error::Transport`] for HTTP errors and [`Error::Other`] for
+    /// serialization errors, or their kind for I/O errors.
+    pub(crate) fn duplicate(&self) -> Self {
+        match self {
+            Self::Http(err) => Self::Transport(err.to_string()),
+            Self::Transport(message) => Self::Transport(message.clone()),
+            Self::Url(err) => Self::Url(*err),
+            Self::Io(err) => Self::Io(std::io::Error::new(err.kind(), err.to_string())),
+            Self::Serialization(err) => Self::Other(format!("Serialization error: {}", err)),
+            Self::NotFound { resource, id } => Self::NotFound {
+                resource: resource.clone(),
+                id: id.clone(),
+            },
+            Self::ImageNotFound { url } => Self::ImageNotFound { url: url.clone() },
+            Self::RateLimited { retry_after } => Self::RateLimited {
+                retry_after: *retry_after,
+            },
+            Self::Server { status, body } => Self::Server {
+                status: *status,
+                body: body.clone(),
+            },
+            Self::Client { status, body } => Self::Client {
+                status: *status,
+                body: body.clone(),
+            },
+            Self::Decode {
+                url,
+                body_snippet,
+                source,
+            } => Self::Decode {
+                url: url.clone(),
+                body_snippet: body_snippet.clone(),
+                source: serde::de::Error::custom(source),
+            },
+            Self::NotRecorded(url) => Self::NotRecorded(url.clone()),
+            Self::Config(message) => Self::Config(message.clone()),
+            Self::InvalidQuery(message) => Self::InvalidQuery(message.clone()),
+            Self::Other(message) => Self::Other(message.clone()),
+        }
+    }
+}
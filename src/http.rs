@@ -0,0 +1,357 @@
+//! HTTP layer shared by every endpoint of the TCGdex client
+
+use std::io::ErrorKind;
+use std::sync::{Arc, OnceLock};
+use std::time::Instant;
+
+use reqwest::header::{HeaderMap, HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+
+use crate::cache::{CacheConfig, MemoryCache};
+use crate::disk_cache::{DiskCache, DiskEntry};
+use crate::error::{Error, Result};
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::retry::{self, RetryPolicy};
+use crate::transport::{Request, ReqwestTransport, Response, Transport};
+
+/// Maximum number of characters of a response body kept in a decoding error
+const BODY_SNIPPET_LEN: usize = 200;
+
+impl Response {
+    /// Turn a client or server error status into the matching error
+    ///
+    /// `resource` and `id` name what was requested, for [`Error::NotFound`].
+    pub(crate) fn error_for_status(self, resource: &str, id: &str) -> Result<Self> {
+        let status = self.status;
+        if !status.is_client_error() && !status.is_server_error() {
+            return Ok(self);
+        }
+
+        let body = String::from_utf8_lossy(&self.body).into_owned();
+        Err(match status {
+            StatusCode::NOT_FOUND => Error::NotFound {
+                resource: resource.to_string(),
+                id: id.to_string(),
+            },
+            StatusCode::TOO_MANY_REQUESTS => Error::RateLimited {
+                retry_after: retry::parse_retry_after(&self.headers),
+            },
+            _ if status.is_server_error() => Error::Server {
+                status: status.as_u16(),
+                body,
+            },
+            _ => Error::Client {
+                status: status.as_u16(),
+                body,
+            },
+        })
+    }
+
+    /// Decode the JSON body of a response to the given URL
+    pub(crate) fn json<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+        serde_json::from_slice(&self.body).map_err(|source| Error::Decode {
+            url: url.to_string(),
+            body_snippet: String::from_utf8_lossy(&self.body)
+                .chars()
+                .take(BODY_SNIPPET_LEN)
+                .collect(),
+            source,
+        })
+    }
+}
+
+/// HTTP client applying the SDK request policies
+#[derive(Debug, Clone)]
+pub(crate) struct HttpClient {
+    /// Transport sending the requests
+    transport: Arc<dyn Transport>,
+    /// Retry policy applied to every request
+    retry: RetryPolicy,
+    /// Rate limiter shared by every clone of this client
+    limiter: Arc<RateLimiter>,
+    /// Response cache shared by every clone of this client
+    cache: Option<Arc<MemoryCache>>,
+    /// Persistent cache revalidated with conditional requests
+    disk_cache: Option<DiskCache>,
+}
+
+impl HttpClient {
+    /// Create a new HTTP client with the default policies
+    pub(crate) fn new(transport: Arc<dyn Transport>) -> Self {
+        Self {
+            transport,
+            retry: RetryPolicy::default(),
+            limiter: Arc::default(),
+            cache: None,
+            disk_cache: None,
+        }
+    }
+
+    /// Get the transport sending the requests
+    pub(crate) fn transport(&self) -> &Arc<dyn Transport> {
+        &self.transport
+    }
+
+    /// Set the transport sending the requests
+    pub(crate) fn set_transport(&mut self, transport: Arc<dyn Transport>) {
+        self.transport = transport;
+    }
+
+    /// Get the retry policy
+    pub(crate) fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry
+    }
+
+    /// Set the retry policy
+    pub(crate) fn set_retry_policy(&mut self, retry: RetryPolicy) {
+        self.retry = retry;
+    }
+
+    /// Get the rate limit
+    pub(crate) fn rate_limit(&self) -> &RateLimit {
+        self.limiter.limit()
+    }
+
+    /// Replace the rate limiter with a new one enforcing the given limit
+    pub(crate) fn set_rate_limit(&mut self, rate_limit: RateLimit) {
+        self.limiter = Arc::new(RateLimiter::new(rate_limit));
+    }
+
+    /// Get the response cache
+    pub(crate) fn cache(&self) -> Option<&MemoryCache> {
+        self.cache.as_deref()
+    }
+
+    /// Replace the response cache with a new empty one, or disable caching
+    pub(crate) fn set_cache(&mut self, config: Option<CacheConfig>) {
+        self.cache = config.map(|config| Arc::new(MemoryCache::new(config)));
+    }
+
+    /// Get the disk cache
+    pub(crate) fn disk_cache(&self) -> Option<&DiskCache> {
+        self.disk_cache.as_ref()
+    }
+
+    /// Set or disable the disk cache
+    pub(crate) fn set_disk_cache(&mut self, disk_cache: Option<DiskCache>) {
+        self.disk_cache = disk_cache;
+    }
+
+    /// Send a GET request for API data, served from the caches when possible
+    ///
+    /// Only successful responses are cached.
+    pub(crate) async fn get(&self, url: &str) -> Result<Response> {
+        let start = Instant::now();
+
+        if let Some(cached) = self.cache.as_ref().and_then(|cache| cache.get(url)) {
+            trace_response(url, &cached, "memory", start);
+            return Ok(cached);
+        }
+
+        let result = match &self.disk_cache {
+            Some(disk_cache) => self.revalidate(disk_cache, url).await,
+            None => self.fetch(url).await.map(|response| (response, "miss")),
+        };
+        let (response, source) = match result {
+            Ok(result) => result,
+            Err(err) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(url, error = %err, "TCGdex request failed");
+                return Err(err);
+            }
+        };
+        trace_response(url, &response, source, start);
+
+        if let Some(cache) = self.cache.as_ref().filter(|_| response.status.is_success()) {
+            cache.insert(url, response.clone());
+        }
+        Ok(response)
+    }
+
+    /// Send a conditional GET request based on the disk cache, and update the cache
+    ///
+    /// A `304 Not Modified` response is turned into a `200 OK` carrying the cached body.
+    /// Also returns whether the response comes from the disk cache, for tracing.
+    async fn revalidate(
+        &self,
+        disk_cache: &DiskCache,
+        url: &str,
+    ) -> Result<(Response, &'static str)> {
+        let stored = disk_cache.load(url).await;
+
+        let mut headers = HeaderMap::new();
+        if let Some((entry, _)) = &stored {
+            let validators = [
+                (IF_NONE_MATCH, &entry.etag),
+                (IF_MODIFIED_SINCE, &entry.last_modified),
+            ];
+            for (name, value) in validators {
+                if let Some(value) = value.as_deref().and_then(|v| HeaderValue::from_str(v).ok()) {
+                    headers.insert(name, value);
+                }
+            }
+        }
+
+        let response = self.fetch_with_headers(url, &headers).await?;
+
+        if response.status == StatusCode::NOT_MODIFIED {
+            if let Some((_, body)) = stored {
+                let response = Response {
+                    status: StatusCode::OK,
+                    headers: response.headers,
+                    body,
+                };
+                return Ok((response, "disk"));
+            }
+        }
+
+        if response.status.is_success() {
+            let entry = DiskEntry::from_headers(url, &response.headers);
+            // The disk cache is best effort, a failed write only costs a future download
+            if entry.has_validators() {
+                let _ = disk_cache.store(&entry, &response.body).await;
+            } else if stored.is_some() {
+                // The stored validators describe an older version of the resource
+                let _ = disk_cache.invalidate(url);
+            }
+        }
+
+        Ok((response, "miss"))
+    }
+
+    /// Download a file served outside of the API data, like an image
+    ///
+    /// Bypasses the caches, but is traced like API requests within a `tcgdex_image` span.
+    pub(crate) async fn download(&self, url: &str) -> Result<Response> {
+        let download = async {
+            let start = Instant::now();
+            let result = self.fetch(url).await;
+            match &result {
+                Ok(response) => trace_response(url, response, "miss", start),
+                #[cfg(feature = "tracing")]
+                Err(err) => tracing::warn!(url, error = %err, "TCGdex download failed"),
+                #[cfg(not(feature = "tracing"))]
+                Err(_) => {}
+            }
+            result
+        };
+
+        #[cfg(feature = "tracing")]
+        let download =
+            tracing::Instrument::instrument(download, tracing::debug_span!("tcgdex_image", url));
+
+        download.await
+    }
+
+    /// Send a GET request bypassing the caches, retrying transient failures
+    ///
+    /// Responses with a non-retryable status are returned as-is.
+    pub(crate) async fn fetch(&self, url: &str) -> Result<Response> {
+        self.fetch_with_headers(url, &HeaderMap::new()).await
+    }
+
+    /// Send a GET request with additional headers, retrying transient failures
+    async fn fetch_with_headers(&self, url: &str, headers: &HeaderMap) -> Result<Response> {
+        let mut attempt = 1;
+
+        loop {
+            let last_attempt = attempt >= self.retry.max_attempts;
+
+            let delay = match self.send(url, headers).await {
+                Ok(response) => {
+                    if last_attempt || !self.retry.is_retryable_status(response.status.as_u16()) {
+                        return Ok(response);
+                    }
+
+                    match self
+                        .retry
+                        .delay(attempt, retry::parse_retry_after(&response.headers))
+                    {
+                        Some(delay) => delay,
+                        None => return Ok(response),
+                    }
+                }
+                Err(err) => {
+                    if last_attempt || !is_transient(&err) {
+                        return Err(err);
+                    }
+
+                    match self.retry.delay(attempt, None) {
+                        Some(delay) => delay,
+                        None => return Err(err),
+                    }
+                }
+            };
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                url,
+                attempt,
+                delay_ms = delay.as_millis() as u64,
+                "retrying TCGdex request"
+            );
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Send a single GET request within the rate limit
+    async fn send(&self, url: &str, headers: &HeaderMap) -> Result<Response> {
+        let _permit = self.limiter.acquire().await;
+
+        self.transport
+            .send(Request {
+                url: url.to_string(),
+                headers: headers.clone(),
+            })
+            .await
+    }
+}
+
+impl Default for HttpClient {
+    fn default() -> Self {
+        // Building a reqwest client loads the TLS roots, so share a single default one
+        static DEFAULT_TRANSPORT: OnceLock<Arc<dyn Transport>> = OnceLock::new();
+
+        let transport = DEFAULT_TRANSPORT.get_or_init(|| Arc::new(ReqwestTransport::default()));
+        Self::new(Arc::clone(transport))
+    }
+}
+
+/// Report a response to the tracing subscriber when the `tracing` feature is enabled
+///
+/// `cache` tells where the response comes from: `memory`, `disk` or `miss`.
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+fn trace_response(url: &str, response: &Response, cache: &str, start: Instant) {
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        url,
+        status = response.status.as_u16(),
+        latency_ms = start.elapsed().as_millis() as u64,
+        bytes = response.body.len(),
+        cache,
+        "TCGdex response"
+    );
+}
+
+/// Check if a request error is worth retrying (timeouts, connection resets, ...)
+fn is_transient(err: &Error) -> bool {
+    match err {
+        Error::Http(err) => {
+            err.is_timeout() || err.is_connect() || err.is_request() || err.is_body()
+        }
+        Error::Io(err) => matches!(
+            err.kind(),
+            ErrorKind::TimedOut
+                | ErrorKind::ConnectionReset
+                | ErrorKind::ConnectionAborted
+                | ErrorKind::ConnectionRefused
+                | ErrorKind::BrokenPipe
+                | ErrorKind::UnexpectedEof
+                | ErrorKind::Interrupted
+        ),
+        _ => false,
+    }
+}
@@ -3,18 +3,40 @@
 //! This SDK provides a convenient way to interact with the TCGdex API,
 //! which is a database for Poku00e9mon Trading Card Game cards, sets, and series.
 
+mod builder;
+mod cache;
 mod client;
+mod disk_cache;
 mod endpoints;
 mod error;
+mod http;
 mod models;
 mod query;
+mod queryable;
+mod rate_limit;
+mod resource_query;
+mod retry;
+mod single_flight;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod transport;
 mod utils;
+mod vcr;
 
+pub use builder::TCGdexBuilder;
+pub use cache::{CacheConfig, MemoryCache};
 pub use client::TCGdex;
-pub use endpoints::Endpoint;
+pub use disk_cache::DiskCache;
+pub use endpoints::{Endpoint, StreamConfig};
 pub use error::{Error, Result};
 pub use models::*;
-pub use query::Query;
+pub use query::{Operator, Query, QueryParam};
+pub use queryable::{FieldValue, Queryable};
+pub use rate_limit::RateLimit;
+pub use resource_query::{CardQuery, ResourceQuery, SerieQuery, SetQuery, SortOrder};
+pub use retry::RetryPolicy;
+pub use transport::{Request, ReqwestTransport, Response, Transport};
+pub use vcr::{RecordingTransport, ReplayTransport};
 
 /// The current version of the SDK
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -72,6 +94,498 @@ mod tests {
         );
     }
 
-    // We would add actual API call tests here if we had mockable test data
-    // For now we'll just test the construction of the SDK and Query builder
+    #[test]
+    fn test_query_fields() {
+        use crate::{CardField, SetField};
+
+        let mut query = Query::new();
+        query
+            .equal(CardField::SetId, "base1")
+            .greater_or_equal_than(CardField::Hp, 100)
+            .equal(CardField::LegalStandard, true)
+            .sort(CardField::DexId, "asc");
+        assert_eq!(
+            query.build(),
+            "?set.id=eq%3Abase1&hp=gte%3A100&legal.standard=eq%3Atrue&sort%3Afield=dexId&sort%3Aorder=asc"
+        );
+
+        assert_eq!("cardCount.total".parse(), Ok(SetField::CardCountTotal));
+        assert!("hP".parse::<CardField>().is_err());
+    }
+
+    #[test]
+    fn test_query_alternatives() {
+        let mut query = Query::new();
+        query.equal_any("rarity", ["Rare", "Rare Holo"]);
+        assert_eq!(query.build(), "?rarity=eq%3ARare%7CRare%20Holo");
+
+        // Alternatives on the same field are merged into a single parameter
+        let mut fire = Query::new();
+        fire.equal("types", "Fire");
+        let mut water = Query::new();
+        water.equal("types", "Water");
+        let mut query = Query::new();
+        query.any_of([fire, water]);
+        assert!(!query.needs_several_requests());
+        assert_eq!(query.build(), "?types=eq%3AFire%7CWater");
+
+        // Other alternatives need one request each, with the common conditions
+        let mut fire = Query::new();
+        fire.equal("types", "Fire");
+        let mut strong = Query::new();
+        strong.greater_than("hp", 200);
+        let mut query = Query::new();
+        query.equal("category", "Pokemon").any_of([fire, strong]);
+        assert!(query.needs_several_requests());
+        assert_eq!(
+            query.build_all().unwrap(),
+            [
+                "?category=eq%3APokemon&types=eq%3AFire",
+                "?category=eq%3APokemon&hp=gt%3A200",
+            ]
+        );
+
+        // Each request would be sorted and paginated on its own
+        query.sort("name", "asc");
+        assert!(matches!(
+            query.build_all(),
+            Err(crate::Error::InvalidQuery(_))
+        ));
+
+        // Every combination of alternatives is a request
+        let mut query = Query::new();
+        for _ in 0..6 {
+            let mut fire = Query::new();
+            fire.equal("types", "Fire");
+            let mut strong = Query::new();
+            strong.greater_than("hp", 200);
+            query.any_of([fire, strong]);
+        }
+        assert!(matches!(
+            query.build_all(),
+            Err(crate::Error::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_query_parse() {
+        use crate::{Error, Operator};
+
+        let mut query = Query::new();
+        query
+            .equal("name", "Pikachu")
+            .greater_than("hp", 100)
+            .contains("rarity", "Rare Holo")
+            .sort("name", "asc")
+            .paginate(2, 50);
+        let parsed = Query::parse(&query.build()).unwrap();
+        assert_eq!(parsed, query);
+        assert_eq!(parsed.build(), query.build());
+
+        let parsed: Query = "name=eq:Pikachu&hp=gt%3A100&sort:field=name"
+            .parse()
+            .unwrap();
+        let params: Vec<_> = parsed
+            .params()
+            .iter()
+            .map(|param| (param.key(), param.operator(), param.value()))
+            .collect();
+        assert_eq!(
+            params,
+            [
+                ("name", Some(Operator::Equal), "Pikachu"),
+                ("hp", Some(Operator::GreaterThan), "100"),
+                ("sort:field", None, "name"),
+            ]
+        );
+
+        assert!(matches!(
+            Query::parse("=Pikachu"),
+            Err(Error::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            Query::parse("?pagination:page=0"),
+            Err(Error::InvalidQuery(_))
+        ));
+
+        // Saved queries keep their alternatives
+        let mut fire = Query::new();
+        fire.equal("types", "Fire");
+        let mut strong = Query::new();
+        strong.greater_than("hp", 200);
+        query.any_of([fire, strong]);
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(serde_json::from_str::<Query>(&json).unwrap(), query);
+
+        // Query strings drop them, matching more records
+        assert!(query.needs_several_requests());
+        let parsed = Query::parse(&query.build()).unwrap();
+        assert!(!parsed.needs_several_requests());
+        assert_ne!(parsed, query);
+    }
+
+    #[test]
+    fn test_query_round_trip() {
+        // Every short value over characters with a meaning in URLs or in the API syntax
+        let alphabet = [
+            ' ', '"', '\'', '\\', '+', '%', '&', '=', ':', '|', '?', '#', 'a', 'é',
+        ];
+        let mut values = vec![String::new()];
+        for _ in 0..3 {
+            values = values
+                .iter()
+                .flat_map(|value| alphabet.iter().map(move |c| format!("{}{}", value, c)))
+                .collect();
+            for value in &values {
+                let mut query = Query::new();
+                query
+                    .contains("name", value.as_str())
+                    .equal(value.as_str(), value.as_str());
+                let parsed = Query::parse(&query.build()).unwrap();
+                assert_eq!(parsed, query, "value {:?}", value);
+                assert_eq!(parsed.build(), query.build());
+            }
+        }
+    }
+
+    // Build a card with only the fields used by the local query tests
+    fn card(id: &str, name: &str, hp: i32, types: &[&str]) -> crate::Card {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "localId": id.rsplit('-').next(),
+            "name": name,
+            "hp": hp,
+            "types": types,
+            "rarity": "Rare",
+            "category": "Pokemon",
+            "set": {"id": "base1", "name": "Base Set", "cardCount": {"total": 102, "official": 102}},
+            "variants": {"normal": true, "reverse": false, "holo": true, "firstEdition": false, "wPromo": false},
+            "legal": {"standard": false, "expanded": false},
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_query_local_evaluation() {
+        use crate::{CardField, Queryable};
+
+        let charizard = card("base1-4", "Charizard", 120, &["Fire"]);
+        assert_eq!(
+            charizard.field_values("hp"),
+            [crate::FieldValue::Number(120.0)]
+        );
+        assert!(charizard.field_values("unknown").is_empty());
+
+        let mut query = Query::new();
+        query
+            .contains(CardField::Name, "CHAR")
+            .greater_than(CardField::Hp, 100);
+        assert!(query.matches(&charizard));
+        query
+            .equal(CardField::Types, "fire|water")
+            .is_null(CardField::Stage);
+        assert!(query.matches(&charizard));
+        query.not_equal(CardField::SetId, "base1");
+        assert!(!query.matches(&charizard));
+
+        // Numbers are compared numerically, not as text
+        let mut query = Query::new();
+        query.less_than(CardField::Hp, 90);
+        assert!(!query.matches(&charizard));
+        assert!(query.matches(&card("base1-58", "Pikachu", 40, &["Lightning"])));
+
+        let mut cards = vec![
+            card("base1-4", "Charizard", 120, &["Fire"]),
+            card("base1-2", "Blastoise", 100, &["Water"]),
+            card("base1-58", "Pikachu", 40, &["Lightning"]),
+            card("base1-46", "Charmander", 50, &["Fire"]),
+        ];
+        let mut query = Query::new();
+        query
+            .not_contains(CardField::Types, "lightning")
+            .sort(CardField::Hp, "desc")
+            .paginate(2, 2);
+        query.apply(&mut cards);
+        let names: Vec<_> = cards.iter().map(|card| card.name.as_str()).collect();
+        assert_eq!(names, ["Charmander"]);
+    }
+
+    // Check that every name of every language parses back to its value
+    fn assert_localized_names<T: crate::models::Localized + std::fmt::Debug>() {
+        let languages = [
+            "en", "fr", "es", "es-mx", "it", "pt-br", "pt-pt", "de", "nl", "pl", "ru", "ja", "ko",
+            "zh-tw", "id", "th", "zh-cn",
+        ];
+        for language in languages {
+            let language: Language = language.parse().unwrap();
+            assert_eq!(T::names(language).len(), T::KNOWN.len());
+            for value in T::KNOWN {
+                let name = value.localized_name(language);
+                assert_eq!(&T::parse_localized(name, language), value);
+                // Names are never ambiguous between languages
+                assert_eq!(&T::parse_any(name), value, "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_localized_names() {
+        use crate::{Category, EnergyType, PokemonType, Rarity, Stage, Suffix, TrainerType};
+
+        assert_localized_names::<PokemonType>();
+        assert_localized_names::<Rarity>();
+        assert_localized_names::<Category>();
+        assert_localized_names::<Stage>();
+        assert_localized_names::<TrainerType>();
+        assert_localized_names::<EnergyType>();
+        assert_localized_names::<Suffix>();
+
+        assert_eq!(
+            PokemonType::parse_localized("FEU", Language::FR),
+            PokemonType::Fire
+        );
+        assert!(!PokemonType::parse_localized("Feu", Language::EN).is_known());
+        assert_eq!("ex".parse(), Ok(Suffix::LowercaseEx));
+        assert_eq!("EX".parse(), Ok(Suffix::Ex));
+
+        assert_eq!(
+            Rarity::parse_localized("SAR", Language::JA),
+            Rarity::SpecialIllustrationRare
+        );
+        assert_eq!(Rarity::Rare.localized_name(Language::RU), "Редкая");
+        // Every value is translated, not only the first ones
+        assert_eq!(Rarity::RareHolo.localized_name(Language::FR), "Holo Rare");
+        assert_eq!(
+            Rarity::parse_localized("Selten Holo", Language::DE),
+            Rarity::RareHolo
+        );
+        assert_eq!(
+            Stage::parse_localized("Niveau Supérieur", Language::FR),
+            Stage::LevelUp
+        );
+        assert_eq!(Stage::Restored.localized_name(Language::DE), "Wiederbelebt");
+        assert_eq!(
+            TrainerType::parse_localized("Machine Technique", Language::FR),
+            TrainerType::TechnicalMachine
+        );
+        assert_eq!(
+            TrainerType::AceSpec.localized_name(Language::ES),
+            "AS TÁCTICO"
+        );
+
+        assert!(Rarity::Common < Rarity::Rare);
+        assert!(Rarity::UltraRare < Rarity::HyperRare);
+        assert!(Rarity::HyperRare < Rarity::Unknown("Crown".to_string()));
+    }
+
+    #[test]
+    fn test_set_card_count() {
+        let count = crate::SetCardCount {
+            total: 258,
+            official: 198,
+            normal: None,
+            reverse: None,
+            holo: None,
+            first_ed: None,
+        };
+        assert_eq!(count.secret_rare_count(), 60);
+        assert_eq!(count.master_set_total(), None);
+
+        let count = crate::SetCardCount {
+            normal: Some(180),
+            reverse: Some(198),
+            holo: Some(18),
+            ..count
+        };
+        assert_eq!(count.master_set_total(), None);
+
+        let count = crate::SetCardCount {
+            first_ed: Some(0),
+            ..count
+        };
+        assert_eq!(count.master_set_total(), Some(396));
+    }
+
+    #[test]
+    fn test_attack_damage_and_cost() {
+        use crate::{DamageModifier, DamageValue, EnergyCost, PokemonType};
+
+        let parse = |text: &str| text.parse::<DamageValue>().unwrap();
+        assert_eq!(parse("30"), DamageValue::fixed(30));
+        assert_eq!(parse("30+").modifier, DamageModifier::Plus);
+        assert_eq!(parse("20×").modifier, DamageModifier::Times);
+        assert_eq!(parse("20x").modifier, DamageModifier::Times);
+        assert_eq!(parse("50-").modifier, DamageModifier::Minus);
+        assert_eq!(parse("20×").to_string(), "20×");
+        assert!("×2".parse::<DamageValue>().is_err());
+        assert!("".parse::<DamageValue>().is_err());
+        let err = "×2".parse::<DamageValue>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid damage: ×2");
+
+        let cost = EnergyCost::new(&[PokemonType::Fire, PokemonType::Colorless, PokemonType::Fire]);
+        assert_eq!(cost.count(&PokemonType::Fire), 2);
+        assert_eq!(cost.count(&PokemonType::Water), 0);
+        assert_eq!(cost.converted_cost(), 3);
+        assert!(cost.can_pay(&[PokemonType::Water, PokemonType::Fire, PokemonType::Fire]));
+        assert!(!cost.can_pay(&[PokemonType::Water, PokemonType::Water, PokemonType::Fire]));
+        assert!(!cost.can_pay(&[PokemonType::Fire, PokemonType::Fire]));
+        assert!(EnergyCost::default().can_pay(&[]));
+    }
+
+    #[test]
+    fn test_dates() {
+        use crate::{Date, Timestamp};
+
+        let date: Date = "1999-01-09".parse().unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (1999, 1, 9));
+        assert_eq!(date.to_string(), "1999-01-09");
+        assert!(date < "2023-03-31".parse().unwrap());
+        assert!("2024-02-29".parse::<Date>().is_ok());
+        assert!(Date::new(0, 1, 1).is_some());
+        assert!(Date::new(10_000, 1, 1).is_none());
+        assert!(Date::new(-1, 12, 31).is_none());
+        let err = "1999/01/09".parse::<Date>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid date: 1999/01/09");
+        for invalid in [
+            "2023-02-29",
+            "2023-13-01",
+            "1999-1-9",
+            "1999-01-09T00:00:00Z",
+            "",
+        ] {
+            assert!(invalid.parse::<Date>().is_err(), "{}", invalid);
+        }
+
+        let timestamp: Timestamp = "2025-08-15T21:42:19+02:00".parse().unwrap();
+        assert_eq!(timestamp.date(), Date::new(2025, 8, 15).unwrap());
+        assert_eq!(timestamp.offset_minutes(), 120);
+        assert_eq!(timestamp.unix_timestamp(), 1_755_286_939);
+        assert_eq!(timestamp.to_string(), "2025-08-15T21:42:19+02:00");
+
+        // Same instant in another offset
+        let utc: Timestamp = "2025-08-15T19:42:19.000Z".parse().unwrap();
+        assert_eq!(utc, timestamp);
+        assert_eq!(utc.to_string(), "2025-08-15T19:42:19Z");
+        let later: Timestamp = "2025-08-15T19:42:19.5Z".parse().unwrap();
+        assert_eq!(later.nanosecond(), 500_000_000);
+        assert!(timestamp < later);
+        for invalid in ["2025-08-15", "2025-08-15T24:00:00Z", "2025-08-15T21:42:19"] {
+            assert!(invalid.parse::<Timestamp>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_conversions() {
+        use crate::{Date, Timestamp};
+
+        let date = Date::new(1999, 1, 9).unwrap();
+        let naive = chrono::NaiveDate::from(date);
+        assert_eq!(naive, chrono::NaiveDate::from_ymd_opt(1999, 1, 9).unwrap());
+        assert_eq!(Date::try_from(naive), Ok(date));
+        let far = chrono::NaiveDate::from_ymd_opt(10_000, 1, 1).unwrap();
+        assert!(Date::try_from(far).is_err());
+
+        let timestamp: Timestamp = "2025-08-15T21:42:19.25+02:00".parse().unwrap();
+        let date_time = chrono::DateTime::<chrono::FixedOffset>::from(timestamp);
+        assert_eq!(date_time.timestamp(), timestamp.unix_timestamp());
+        assert_eq!(date_time.timestamp_subsec_nanos(), 250_000_000);
+        assert_eq!(date_time.offset().local_minus_utc(), 2 * 3600);
+    }
+
+    #[test]
+    fn test_retry_policy_backoff() {
+        use std::time::Duration;
+
+        let policy = crate::RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+            jitter: false,
+            ..Default::default()
+        };
+        assert_eq!(policy.delay(1, None), Some(Duration::from_millis(100)));
+        assert_eq!(policy.delay(2, None), Some(Duration::from_millis(200)));
+        assert_eq!(policy.delay(4, None), Some(Duration::from_millis(500)));
+
+        // Retry-After takes precedence, unless it exceeds the maximum delay
+        let retry_after = Some(Duration::from_millis(300));
+        assert_eq!(policy.delay(1, retry_after), retry_after);
+        assert_eq!(policy.delay(1, Some(Duration::from_secs(60))), None);
+    }
+
+    /// Transport answering with a serie after failing a number of times
+    #[derive(Debug)]
+    struct FlakyTransport {
+        failures: std::sync::atomic::AtomicU32,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::Transport for FlakyTransport {
+        async fn send(&self, request: crate::Request) -> crate::Result<crate::Response> {
+            use std::sync::atomic::Ordering;
+
+            assert_eq!(request.url, "https://example.com/api/en/series/base");
+            if self.failures.fetch_sub(1, Ordering::SeqCst) > 0 {
+                let reset = std::io::Error::from(std::io::ErrorKind::ConnectionReset);
+                return Err(reset.into());
+            }
+
+            Ok(crate::Response {
+                status: reqwest::StatusCode::OK,
+                headers: Default::default(),
+                body: r#"{"id": "base", "name": "Base", "sets": []}"#.into(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_transport() {
+        let transport = FlakyTransport { failures: 1.into() };
+        let mut client = TCGdex::with_transport("https://example.com/api", Language::EN, transport);
+        client.set_retry_policy(crate::RetryPolicy {
+            base_delay: std::time::Duration::ZERO,
+            ..Default::default()
+        });
+
+        let serie = client.serie.get("base").await.unwrap();
+        assert_eq!(serie.id, "base");
+
+        #[allow(deprecated)]
+        {
+            assert!(client.client().is_none());
+            assert!(TCGdex::default().client().is_some());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_model_fetch_uses_client_options() {
+        use crate::endpoints::Fetchable;
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/en/series/base"))
+            .and(query_param("source", "tests"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                r#"{"id": "base", "name": "Base", "sets": []}"#,
+                "application/json",
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let tcgdex = TCGdex::builder()
+            .endpoint(server.uri())
+            .language(Language::IT)
+            .fallback_language(Language::EN)
+            .default_query_param("source", "tests")
+            .build()
+            .unwrap();
+
+        // Same fallback and default query parameters as the endpoint
+        let url = format!("{}/it/series/base", server.uri());
+        let serie = crate::Serie::fetch_with(&tcgdex, &url).await.unwrap();
+        assert_eq!(serie.name, "Base");
+    }
 }
@@ -0,0 +1,155 @@
+//! Structured attack damage and energy cost
+
+use std::fmt;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::models::PokemonType;
+
+/// How the damage of an attack can change, shown after its base damage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DamageModifier {
+    /// Fixed damage, e.g. `30`
+    None,
+    /// At least the base damage, more depending on the effect, e.g. `30+`
+    Plus,
+    /// The base damage times a number depending on the effect, e.g. `20×`
+    Times,
+    /// At most the base damage, less depending on the effect, e.g. `50-`
+    Minus,
+}
+
+/// Damage of an attack, parsed from the API value, e.g. `30+`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DamageValue {
+    /// Damage printed on the card, before the effect applies
+    pub base: u16,
+    /// How the effect of the attack changes the damage
+    pub modifier: DamageModifier,
+}
+
+/// Error returned when a text is not a valid [`DamageValue`]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid damage: {text}")]
+pub struct ParseDamageError {
+    text: String,
+}
+
+/// Energy needed to use an attack, counted by type
+///
+/// [`PokemonType::Colorless`] Energy can be paid with Energy of any type.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnergyCost {
+    // Count of each type, in the order of the cost
+    counts: Vec<(PokemonType, u32)>,
+}
+
+impl DamageValue {
+    /// Create a fixed damage
+    pub fn fixed(base: u16) -> Self {
+        Self {
+            base,
+            modifier: DamageModifier::None,
+        }
+    }
+}
+
+impl fmt::Display for DamageValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let modifier = match self.modifier {
+            DamageModifier::None => "",
+            DamageModifier::Plus => "+",
+            DamageModifier::Times => "×",
+            DamageModifier::Minus => "-",
+        };
+        write!(f, "{}{}", self.base, modifier)
+    }
+}
+
+impl FromStr for DamageValue {
+    type Err = ParseDamageError;
+
+    /// Parse a damage like `30`, `30+`, `20×` or `50-`, also accepting `x` and `*` for times
+    fn from_str(text: &str) -> Result<Self, ParseDamageError> {
+        let invalid = || ParseDamageError {
+            text: text.to_string(),
+        };
+        let trimmed = text.trim();
+        let digits_end = trimmed
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(trimmed.len());
+        let (base, modifier) = trimmed.split_at(digits_end);
+        let modifier = match modifier.trim() {
+            "" => DamageModifier::None,
+            "+" => DamageModifier::Plus,
+            "×" | "x" | "X" | "*" => DamageModifier::Times,
+            "-" | "−" => DamageModifier::Minus,
+            _ => return Err(invalid()),
+        };
+        let base = base.parse().map_err(|_| invalid())?;
+        Ok(Self { base, modifier })
+    }
+}
+
+impl EnergyCost {
+    /// Count the Energy of each type of a cost, as listed by the API
+    pub fn new(cost: &[PokemonType]) -> Self {
+        let mut counts: Vec<(PokemonType, u32)> = Vec::new();
+        for type_ in cost {
+            match counts.iter_mut().find(|(counted, _)| counted == type_) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((type_.clone(), 1)),
+            }
+        }
+        Self { counts }
+    }
+
+    /// Get the number of Energy of this type in the cost
+    pub fn count(&self, type_: &PokemonType) -> u32 {
+        self.counts
+            .iter()
+            .find(|(counted, _)| counted == type_)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    /// Get the types of the cost with their number of Energy, in the order of the cost
+    pub fn counts(&self) -> impl Iterator<Item = (&PokemonType, u32)> {
+        self.counts.iter().map(|(type_, count)| (type_, *count))
+    }
+
+    /// Get the total number of Energy of the cost, whatever their type
+    pub fn converted_cost(&self) -> u32 {
+        self.counts.iter().map(|(_, count)| count).sum()
+    }
+
+    /// Check if the cost is free
+    pub fn is_free(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Check if the attached Energy, one type per Energy card, can pay the cost
+    pub fn can_pay(&self, energies: &[PokemonType]) -> bool {
+        let mut colorless = 0;
+        let mut specific_paid = 0;
+        for (type_, count) in &self.counts {
+            if *type_ == PokemonType::Colorless {
+                colorless += count;
+                continue;
+            }
+            let available = energies.iter().filter(|energy| *energy == type_).count() as u32;
+            if available < *count {
+                return false;
+            }
+            specific_paid += count;
+        }
+        // Colorless Energy is paid with whatever is left
+        energies.len() as u32 >= specific_paid + colorless
+    }
+}
+
+impl From<&[PokemonType]> for EnergyCost {
+    fn from(cost: &[PokemonType]) -> Self {
+        Self::new(cost)
+    }
+}
@@ -6,7 +6,11 @@ use serde::{Deserialize, Serialize};
 use crate::endpoints::{Fetchable, Listable};
 use crate::error::Result;
 use crate::models::common::*;
-use crate::models::{Extension, Quality, SetResume};
+use crate::models::{
+    CardField, CardPricing, CardmarketPricing, Category, EnergyType, Extension, PokemonType,
+    Quality, Rarity, SetResume, Stage, Suffix, TcgplayerPricing, Timestamp, TrainerType,
+};
+use crate::queryable::{localized_values, values, FieldValue, Queryable};
 use crate::utils;
 
 /// Pokémon TCG Card, contains all information about a specific card
@@ -15,11 +19,15 @@ pub struct Card {
     /// Card illustrator
     pub illustrator: Option<String>,
     /// Card rarity
-    pub rarity: String,
+    pub rarity: Rarity,
     /// Card category
-    pub category: String,
+    pub category: Category,
     /// The card's possible variants
     pub variants: CardVariants,
+    /// Detailed information about each variant of the card
+    pub variants_detailed: Option<Vec<CardVariantDetailed>>,
+    /// The boosters the card can be found in, `None` if it is in every booster of its set
+    pub boosters: Option<Vec<Booster>>,
     /// Resume of the set the card belongs to
     pub set: SetResume,
     /// The Pokémon Pokédex IDs
@@ -29,7 +37,7 @@ pub struct Card {
     #[serde(deserialize_with = "crate::utils::deserialize_string_or_number_to_i32")]
     pub hp: Option<i32>,
     /// Types of the Pokémon
-    pub types: Option<Vec<String>>,
+    pub types: Option<Vec<PokemonType>>,
     /// Name of the Pokémon this one evolves from
     #[serde(rename = "evolvesFrom")]
     pub evolves_from: Option<String>,
@@ -38,9 +46,9 @@ pub struct Card {
     /// The Pokémon level
     pub level: Option<String>,
     /// The Pokémon stage
-    pub stage: Option<String>,
+    pub stage: Option<Stage>,
     /// The Pokémon suffix
-    pub suffix: Option<String>,
+    pub suffix: Option<Suffix>,
     /// The item the Pokémon has
     pub item: Option<CardItem>,
     /// The card abilities
@@ -57,10 +65,10 @@ pub struct Card {
     pub effect: Option<String>,
     /// The trainer sub-type
     #[serde(rename = "trainerType")]
-    pub trainer_type: Option<String>,
+    pub trainer_type: Option<TrainerType>,
     /// The energy sub-type
     #[serde(rename = "energyType")]
-    pub energy_type: Option<String>,
+    pub energy_type: Option<EnergyType>,
     /// The card regulation mark
     #[serde(rename = "regulationMark")]
     pub regulation_mark: Option<String>,
@@ -75,9 +83,36 @@ pub struct Card {
     pub name: String,
     /// Card image URL (without extension and quality)
     pub image: Option<String>,
+    /// Card prices on the marketplaces, when known
+    pub pricing: Option<CardPricing>,
+    /// Date and time of the last update of the card data, `None` if missing or malformed
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_lenient_timestamp"
+    )]
+    pub updated: Option<Timestamp>,
 }
 
 impl Card {
+    /// Get the highest base damage of the attacks of the card, `None` without damaging attack
+    pub fn max_base_damage(&self) -> Option<u16> {
+        self.attacks
+            .iter()
+            .flatten()
+            .filter_map(CardAttack::damage_value)
+            .map(|damage| damage.base)
+            .max()
+    }
+
+    /// Check if the card can be found in the booster with this ID
+    ///
+    /// Cards without booster list can be found in every booster of their set.
+    pub fn is_in_booster(&self, booster_id: &str) -> bool {
+        self.boosters
+            .as_ref()
+            .is_none_or(|boosters| boosters.iter().any(|booster| booster.id == booster_id))
+    }
+
     /// Get the full image URL with the specified quality and extension
     pub fn get_image_url(&self, quality: Quality, extension: Extension) -> Option<String> {
         self.image
@@ -94,7 +129,7 @@ impl Card {
     ) -> Result<Option<bytes::Bytes>> {
         match self.get_image_url(quality, extension) {
             Some(url) => {
-                let bytes = utils::download_image(tcgdex.client(), &url).await?;
+                let bytes = utils::download_image(tcgdex.http(), &url).await?;
                 Ok(Some(bytes))
             }
             None => Ok(None),
@@ -109,5 +144,98 @@ impl Fetchable for Card {
 
 #[async_trait]
 impl Listable for Card {
-    // Using the default implementation
+    fn merge_key(&self) -> Option<String> {
+        Some(self.id.clone())
+    }
+}
+
+impl Card {
+    // Get the Cardmarket prices, if known
+    fn cardmarket(&self) -> Option<&CardmarketPricing> {
+        self.pricing.as_ref()?.cardmarket.as_ref()
+    }
+
+    // Get the TCGplayer prices, if known
+    fn tcgplayer(&self) -> Option<&TcgplayerPricing> {
+        self.pricing.as_ref()?.tcgplayer.as_ref()
+    }
+}
+
+impl Queryable for Card {
+    fn field_values(&self, key: &str) -> Vec<FieldValue> {
+        let Ok(field) = key.parse() else {
+            return Vec::new();
+        };
+        match field {
+            CardField::Id => values([self.id.as_str()]),
+            CardField::LocalId => values([self.local_id.as_str()]),
+            CardField::Name => values([self.name.as_str()]),
+            CardField::Category => localized_values([&self.category]),
+            CardField::Illustrator => values(self.illustrator.as_deref()),
+            CardField::Rarity => localized_values([&self.rarity]),
+            CardField::SetId => values([self.set.id.as_str()]),
+            CardField::SetName => values([self.set.name.as_str()]),
+            CardField::DexId => values(self.dex_ids.iter().flatten().copied()),
+            CardField::Hp => values(self.hp),
+            CardField::Types => localized_values(self.types.iter().flatten()),
+            CardField::EvolveFrom => values(self.evolves_from.as_deref()),
+            CardField::Description => values(self.description.as_deref()),
+            CardField::Level => values(self.level.as_deref()),
+            CardField::Stage => localized_values(&self.stage),
+            CardField::Suffix => localized_values(&self.suffix),
+            CardField::ItemName => values(self.item.iter().filter_map(|item| item.name.as_deref())),
+            CardField::AbilityName => values(
+                self.abilities
+                    .iter()
+                    .flatten()
+                    .filter_map(|ability| ability.name.as_deref()),
+            ),
+            CardField::AttackName => values(
+                self.attacks
+                    .iter()
+                    .flatten()
+                    .filter_map(|attack| attack.name.as_deref()),
+            ),
+            CardField::WeaknessType => localized_values(
+                self.weaknesses
+                    .iter()
+                    .flatten()
+                    .map(|weakness| &weakness.type_),
+            ),
+            CardField::ResistanceType => localized_values(
+                self.resistances
+                    .iter()
+                    .flatten()
+                    .map(|resistance| &resistance.type_),
+            ),
+            CardField::Retreat => values(self.retreat),
+            CardField::Effect => values(self.effect.as_deref()),
+            CardField::TrainerType => localized_values(&self.trainer_type),
+            CardField::EnergyType => localized_values(&self.energy_type),
+            CardField::RegulationMark => values(self.regulation_mark.as_deref()),
+            CardField::LegalStandard => values([self.legal.standard]),
+            CardField::LegalExpanded => values([self.legal.expanded]),
+            CardField::BoosterId => values(
+                self.boosters
+                    .iter()
+                    .flatten()
+                    .map(|booster| booster.id.as_str()),
+            ),
+            CardField::CardmarketAverage => values(self.cardmarket().and_then(|price| price.avg)),
+            CardField::CardmarketLow => values(self.cardmarket().and_then(|price| price.low)),
+            CardField::CardmarketTrend => values(self.cardmarket().and_then(|price| price.trend)),
+            CardField::TcgplayerNormalMarket => values(
+                self.tcgplayer()
+                    .and_then(|price| price.normal.as_ref()?.market_price),
+            ),
+            CardField::TcgplayerHolofoilMarket => values(
+                self.tcgplayer()
+                    .and_then(|price| price.holofoil.as_ref()?.market_price),
+            ),
+            CardField::TcgplayerReverseHolofoilMarket => values(
+                self.tcgplayer()
+                    .and_then(|price| price.reverse_holofoil.as_ref()?.market_price),
+            ),
+        }
+    }
 }
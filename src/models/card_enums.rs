@@ -0,0 +1,988 @@
+//! Localized card properties: rarity, category, stage, trainer and energy types, suffix
+//!
+//! The API names these values in the requested language. Values missing from
+//! the name table of a language use their English name, and names matching no
+//! table parse to the `Unknown` variant of their enum.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::endpoints::{Endpoint, Listable};
+use crate::error::Result;
+use crate::models::localized::{localized_enum, Localized};
+use crate::models::{Language, StringEndpoint};
+
+/// Card rarity
+///
+/// Rarities are ordered from the most to the least common, roughly following
+/// the pull rates of the sets they appear in. Unknown rarities come last.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Rarity {
+    /// Card without rarity symbol
+    None,
+    /// Common card
+    Common,
+    /// Uncommon card
+    Uncommon,
+    /// Rare card
+    Rare,
+    /// Holographic rare card
+    RareHolo,
+    /// Rare LV.X card
+    RareHoloLvX,
+    /// Rare Prime card
+    RarePrime,
+    /// Amazing rare card
+    AmazingRare,
+    /// Radiant rare card
+    RadiantRare,
+    /// ACE SPEC rare card
+    AceSpecRare,
+    /// Holographic rare Pokémon V card
+    HoloRareV,
+    /// Holographic rare Pokémon VMAX card
+    HoloRareVmax,
+    /// Holographic rare Pokémon VSTAR card
+    HoloRareVstar,
+    /// Double rare card
+    DoubleRare,
+    /// Ultra rare card
+    UltraRare,
+    /// Shiny rare card
+    ShinyRare,
+    /// Illustration rare card
+    IllustrationRare,
+    /// Special illustration rare card
+    SpecialIllustrationRare,
+    /// Secret rare card
+    SecretRare,
+    /// Hyper rare card
+    HyperRare,
+    /// Rarity not known by this version of the SDK, with its name as sent by the API
+    Unknown(String),
+}
+
+impl Rarity {
+    /// Every known rarity, from the most to the least common
+    pub const ALL: &'static [Rarity] = &[
+        Self::None,
+        Self::Common,
+        Self::Uncommon,
+        Self::Rare,
+        Self::RareHolo,
+        Self::RareHoloLvX,
+        Self::RarePrime,
+        Self::AmazingRare,
+        Self::RadiantRare,
+        Self::AceSpecRare,
+        Self::HoloRareV,
+        Self::HoloRareVmax,
+        Self::HoloRareVstar,
+        Self::DoubleRare,
+        Self::UltraRare,
+        Self::ShinyRare,
+        Self::IllustrationRare,
+        Self::SpecialIllustrationRare,
+        Self::SecretRare,
+        Self::HyperRare,
+    ];
+}
+
+localized_enum!(Rarity, "rarity");
+
+/// English rarity names
+const RARITIES_EN: [&str; 20] = [
+    "None",
+    "Common",
+    "Uncommon",
+    "Rare",
+    "Rare Holo",
+    "Rare Holo LV.X",
+    "Rare PRIME",
+    "Amazing Rare",
+    "Radiant Rare",
+    "ACE SPEC Rare",
+    "Holo Rare V",
+    "Holo Rare VMAX",
+    "Holo Rare VSTAR",
+    "Double rare",
+    "Ultra Rare",
+    "Shiny rare",
+    "Illustration rare",
+    "Special illustration rare",
+    "Secret Rare",
+    "Hyper rare",
+];
+
+/// French rarity names
+const RARITIES_FR: [&str; 20] = [
+    "None",
+    "Commune",
+    "Peu Commune",
+    "Rare",
+    "Holo Rare",
+    "Holo Rare LV.X",
+    "Rare Prime",
+    "Magnifique Rare",
+    "Radieux Rare",
+    "Rare HIGH-TECH",
+    "Holo Rare V",
+    "Holo Rare VMAX",
+    "Holo Rare VSTAR",
+    "Double Rare",
+    "Ultra Rare",
+    "Chromatique Rare",
+    "Illustration Rare",
+    "Illustration Spéciale Rare",
+    "Secrète Rare",
+    "Hyper Rare",
+];
+
+/// Spanish rarity names
+const RARITIES_ES: [&str; 20] = [
+    "None",
+    "Común",
+    "Poco Común",
+    "Rara",
+    "Rara Holo",
+    "Rara Holo Nivel X",
+    "Rara Prime",
+    "Rara Asombrosa",
+    "Rara Radiante",
+    "Rara AS TÁCTICO",
+    "Rara Holo V",
+    "Rara Holo VMAX",
+    "Rara Holo ASTRO",
+    "Rara Doble",
+    "Ultra Rara",
+    "Rara Variocolor",
+    "Ilustración Rara",
+    "Ilustración Especial Rara",
+    "Rara Secreta",
+    "Hiper Rara",
+];
+
+/// Italian rarity names
+const RARITIES_IT: [&str; 20] = [
+    "None",
+    "Comune",
+    "Non Comune",
+    "Rara",
+    "Rara Holo",
+    "Rara Holo LV.X",
+    "Rara Prime",
+    "Rara Meravigliosa",
+    "Rara Radiosa",
+    "Rara ASSO TATTICO",
+    "Rara Holo V",
+    "Rara Holo VMAX",
+    "Rara Holo V ASTRO",
+    "Doppia Rara",
+    "Ultra Rara",
+    "Rara Cromatica",
+    "Rara Illustrazione",
+    "Rara Illustrazione Speciale",
+    "Rara Segreta",
+    "Iper Rara",
+];
+
+/// Portuguese rarity names
+const RARITIES_PT: [&str; 20] = [
+    "None",
+    "Comum",
+    "Incomum",
+    "Rara",
+    "Rara Holo",
+    "Rara Holo NV.X",
+    "Rara Prime",
+    "Rara Incrível",
+    "Rara Radiante",
+    "Rara ÁS TÁTICO",
+    "Rara Holo V",
+    "Rara Holo VMAX",
+    "Rara Holo ASTRO",
+    "Rara Dupla",
+    "Ultra Rara",
+    "Rara Brilhante",
+    "Rara Ilustração",
+    "Rara Ilustração Especial",
+    "Rara Secreta",
+    "Hiper Rara",
+];
+
+/// German rarity names
+const RARITIES_DE: [&str; 20] = [
+    "None",
+    "Häufig",
+    "Nicht so häufig",
+    "Selten",
+    "Selten Holo",
+    "Selten Holo LV.X",
+    "Selten Prime",
+    "Fantastisch Selten",
+    "Strahlend Selten",
+    "ASS-SPEC Selten",
+    "Holo Selten V",
+    "Holo Selten VMAX",
+    "Holo Selten VSTAR",
+    "Doppelselten",
+    "Ultraselten",
+    "Schillernd Selten",
+    "Illustration Selten",
+    "Spezielle Illustration Selten",
+    "Geheim Selten",
+    "Hyperselten",
+];
+
+/// Dutch rarity names
+const RARITIES_NL: [&str; 20] = [
+    "None",
+    "Gewoon",
+    "Ongewoon",
+    "Zeldzaam",
+    "Zeldzaam Holo",
+    "Zeldzaam Holo LV.X",
+    "Zeldzaam Prime",
+    "Verbazingwekkend Zeldzaam",
+    "Stralend Zeldzaam",
+    "ACE SPEC Zeldzaam",
+    "Holo Zeldzaam V",
+    "Holo Zeldzaam VMAX",
+    "Holo Zeldzaam VSTAR",
+    "Dubbel Zeldzaam",
+    "Ultra Zeldzaam",
+    "Glanzend Zeldzaam",
+    "Illustratie Zeldzaam",
+    "Speciale Illustratie Zeldzaam",
+    "Geheim Zeldzaam",
+    "Hyper Zeldzaam",
+];
+
+/// Polish rarity names
+const RARITIES_PL: [&str; 20] = [
+    "None",
+    "Pospolita",
+    "Niepospolita",
+    "Rzadka",
+    "Rzadka Holo",
+    "Rzadka Holo LV.X",
+    "Rzadka Prime",
+    "Niesamowita Rzadka",
+    "Promienna Rzadka",
+    "Rzadka ACE SPEC",
+    "Holo Rzadka V",
+    "Holo Rzadka VMAX",
+    "Holo Rzadka VSTAR",
+    "Podwójnie Rzadka",
+    "Ultra Rzadka",
+    "Błyszcząca Rzadka",
+    "Rzadka Ilustracja",
+    "Specjalna Rzadka Ilustracja",
+    "Sekretna Rzadka",
+    "Hiper Rzadka",
+];
+
+/// Russian rarity names
+const RARITIES_RU: [&str; 20] = [
+    "None",
+    "Обычная",
+    "Необычная",
+    "Редкая",
+    "Редкая голо",
+    "Редкая голо LV.X",
+    "Редкая Prime",
+    "Удивительная редкая",
+    "Сияющая редкая",
+    "Редкая ACE SPEC",
+    "Голо редкая V",
+    "Голо редкая VMAX",
+    "Голо редкая VSTAR",
+    "Двойная редкая",
+    "Ультраредкая",
+    "Блестящая редкая",
+    "Редкая иллюстрация",
+    "Особая редкая иллюстрация",
+    "Секретная редкая",
+    "Гиперредкая",
+];
+
+/// Asian rarity names, the letter codes printed on the cards
+///
+/// Rarities without their own code keep their English name.
+const RARITIES_ASIA: [&str; 20] = [
+    "None",
+    "C",
+    "U",
+    "R",
+    "Rare Holo",
+    "Rare Holo LV.X",
+    "Rare PRIME",
+    "A",
+    "K",
+    "ACE",
+    "Holo Rare V",
+    "Holo Rare VMAX",
+    "Holo Rare VSTAR",
+    "RR",
+    "SR",
+    "S",
+    "AR",
+    "SAR",
+    "HR",
+    "UR",
+];
+
+impl Localized for Rarity {
+    const KNOWN: &'static [Self] = Self::ALL;
+
+    fn names(language: Language) -> &'static [&'static str] {
+        match language {
+            Language::FR => &RARITIES_FR,
+            Language::ES | Language::ES_MX => &RARITIES_ES,
+            Language::IT => &RARITIES_IT,
+            Language::PT_BR | Language::PT_PT => &RARITIES_PT,
+            Language::DE => &RARITIES_DE,
+            Language::NL => &RARITIES_NL,
+            Language::PL => &RARITIES_PL,
+            Language::RU => &RARITIES_RU,
+            Language::JA
+            | Language::KO
+            | Language::ZH_TW
+            | Language::ZH_CN
+            | Language::ID
+            | Language::TH => &RARITIES_ASIA,
+            Language::EN => &RARITIES_EN,
+        }
+    }
+
+    fn unknown(name: String) -> Self {
+        Self::Unknown(name)
+    }
+
+    fn unknown_name(&self) -> Option<&str> {
+        match self {
+            Self::Unknown(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+/// Card category
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Category {
+    /// Pokémon card
+    Pokemon,
+    /// Trainer card
+    Trainer,
+    /// Energy card
+    Energy,
+    /// Category not known by this version of the SDK, with its name as sent by the API
+    Unknown(String),
+}
+
+impl Category {
+    /// Every known category
+    pub const ALL: &'static [Category] = &[Self::Pokemon, Self::Trainer, Self::Energy];
+}
+
+localized_enum!(Category, "category");
+
+impl Localized for Category {
+    const KNOWN: &'static [Self] = Self::ALL;
+
+    fn names(language: Language) -> &'static [&'static str] {
+        match language {
+            Language::EN => &["Pokemon", "Trainer", "Energy"],
+            Language::FR => &["Pokémon", "Dresseur", "Énergie"],
+            Language::ES | Language::ES_MX => &["Pokémon", "Entrenador", "Energía"],
+            Language::IT => &["Pokémon", "Allenatore", "Energia"],
+            Language::PT_BR | Language::PT_PT => &["Pokémon", "Treinador", "Energia"],
+            Language::DE | Language::NL => &["Pokémon", "Trainer", "Energie"],
+            Language::PL => &["Pokémon", "Trener", "Energia"],
+            Language::RU => &["Покемон", "Тренер", "Энергия"],
+            Language::JA => &["ポケモン", "トレーナーズ", "エネルギー"],
+            Language::KO => &["포켓몬", "트레이너스", "에너지"],
+            Language::ZH_TW => &["寶可夢", "訓練家", "能量"],
+            Language::ZH_CN => &["宝可梦", "训练家", "能量"],
+            Language::ID => &["Pokémon", "Trainer", "Energi"],
+            Language::TH => &["โปเกมอน", "เทรนเนอร์", "พลังงาน"],
+        }
+    }
+
+    fn unknown(name: String) -> Self {
+        Self::Unknown(name)
+    }
+
+    fn unknown_name(&self) -> Option<&str> {
+        match self {
+            Self::Unknown(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+/// Evolution stage of a Pokémon
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Stage {
+    /// Basic Pokémon
+    Basic,
+    /// Stage 1 Pokémon
+    Stage1,
+    /// Stage 2 Pokémon
+    Stage2,
+    /// Pokémon BREAK
+    Break,
+    /// Pokémon LV.X, leveled up from another Pokémon
+    LevelUp,
+    /// Mega Evolution Pokémon
+    Mega,
+    /// Restored Pokémon, played from a fossil
+    Restored,
+    /// Pokémon V-UNION
+    VUnion,
+    /// Pokémon VMAX
+    Vmax,
+    /// Pokémon VSTAR
+    Vstar,
+    /// Stage not known by this version of the SDK, with its name as sent by the API
+    Unknown(String),
+}
+
+impl Stage {
+    /// Every known stage
+    pub const ALL: &'static [Stage] = &[
+        Self::Basic,
+        Self::Stage1,
+        Self::Stage2,
+        Self::Break,
+        Self::LevelUp,
+        Self::Mega,
+        Self::Restored,
+        Self::VUnion,
+        Self::Vmax,
+        Self::Vstar,
+    ];
+}
+
+localized_enum!(Stage, "stage");
+
+/// English stage names, also used by languages without translation
+const STAGES_EN: [&str; 10] = [
+    "Basic", "Stage1", "Stage2", "BREAK", "LEVEL-UP", "MEGA", "RESTORED", "V-UNION", "VMAX",
+    "VSTAR",
+];
+
+// The BREAK, V-UNION, VMAX and VSTAR mechanics keep their English name in
+// every language, and the Asian tables only translate the evolution stages
+const STAGES_FR: [&str; 10] = [
+    "De base",
+    "Niveau 1",
+    "Niveau 2",
+    "BREAK",
+    "Niveau Supérieur",
+    "Méga",
+    "Restauré",
+    "V-UNION",
+    "VMAX",
+    "VSTAR",
+];
+const STAGES_ES: [&str; 10] = [
+    "Básico",
+    "Fase 1",
+    "Fase 2",
+    "BREAK",
+    "Subir de nivel",
+    "Mega",
+    "Restaurado",
+    "V-UNION",
+    "VMAX",
+    "VSTAR",
+];
+const STAGES_IT: [&str; 10] = [
+    "Base",
+    "Fase 1",
+    "Fase 2",
+    "BREAK",
+    "Livello superiore",
+    "Mega",
+    "Ripristinato",
+    "V-UNION",
+    "VMAX",
+    "VSTAR",
+];
+const STAGES_PT: [&str; 10] = [
+    "Básico",
+    "Estágio 1",
+    "Estágio 2",
+    "BREAK",
+    "Subir de nível",
+    "Mega",
+    "Restaurado",
+    "V-UNION",
+    "VMAX",
+    "VSTAR",
+];
+const STAGES_DE: [&str; 10] = [
+    "Basis",
+    "Phase 1",
+    "Phase 2",
+    "BREAK",
+    "Level-Up",
+    "Mega",
+    "Wiederbelebt",
+    "V-UNION",
+    "VMAX",
+    "VSTAR",
+];
+const STAGES_NL: [&str; 10] = [
+    "Basis",
+    "Stadium 1",
+    "Stadium 2",
+    "BREAK",
+    "Level-Up",
+    "Mega",
+    "Hersteld",
+    "V-UNION",
+    "VMAX",
+    "VSTAR",
+];
+const STAGES_PL: [&str; 10] = [
+    "Podstawowy",
+    "Etap 1",
+    "Etap 2",
+    "BREAK",
+    "Awans",
+    "Mega",
+    "Przywrócony",
+    "V-UNION",
+    "VMAX",
+    "VSTAR",
+];
+const STAGES_RU: [&str; 10] = [
+    "Базовый",
+    "Стадия 1",
+    "Стадия 2",
+    "BREAK",
+    "Повышение уровня",
+    "Мега",
+    "Восстановленный",
+    "V-UNION",
+    "VMAX",
+    "VSTAR",
+];
+const STAGES_JA: [&str; 10] = [
+    "たね", "1進化", "2進化", "BREAK", "LEVEL-UP", "MEGA", "RESTORED", "V-UNION", "VMAX", "VSTAR",
+];
+const STAGES_KO: [&str; 10] = [
+    "기본", "1진화", "2진화", "BREAK", "LEVEL-UP", "MEGA", "RESTORED", "V-UNION", "VMAX", "VSTAR",
+];
+const STAGES_ZH_TW: [&str; 10] = [
+    "基礎",
+    "1階進化",
+    "2階進化",
+    "BREAK",
+    "LEVEL-UP",
+    "MEGA",
+    "RESTORED",
+    "V-UNION",
+    "VMAX",
+    "VSTAR",
+];
+const STAGES_ZH_CN: [&str; 10] = [
+    "基础",
+    "1阶进化",
+    "2阶进化",
+    "BREAK",
+    "LEVEL-UP",
+    "MEGA",
+    "RESTORED",
+    "V-UNION",
+    "VMAX",
+    "VSTAR",
+];
+
+impl Localized for Stage {
+    const KNOWN: &'static [Self] = Self::ALL;
+
+    fn names(language: Language) -> &'static [&'static str] {
+        match language {
+            Language::FR => &STAGES_FR,
+            Language::ES | Language::ES_MX => &STAGES_ES,
+            Language::IT => &STAGES_IT,
+            Language::PT_BR | Language::PT_PT => &STAGES_PT,
+            Language::DE => &STAGES_DE,
+            Language::NL => &STAGES_NL,
+            Language::PL => &STAGES_PL,
+            Language::RU => &STAGES_RU,
+            Language::JA => &STAGES_JA,
+            Language::KO => &STAGES_KO,
+            Language::ZH_TW => &STAGES_ZH_TW,
+            Language::ZH_CN => &STAGES_ZH_CN,
+            _ => &STAGES_EN,
+        }
+    }
+
+    fn unknown(name: String) -> Self {
+        Self::Unknown(name)
+    }
+
+    fn unknown_name(&self) -> Option<&str> {
+        match self {
+            Self::Unknown(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+/// Sub-type of a Trainer card
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum TrainerType {
+    /// Supporter card, one per turn
+    Supporter,
+    /// Item card
+    Item,
+    /// Stadium card
+    Stadium,
+    /// Pokémon Tool card
+    Tool,
+    /// ACE SPEC card, one per deck
+    AceSpec,
+    /// Technical Machine card
+    TechnicalMachine,
+    /// Goldenrod Game Corner card
+    GoldenrodGameCorner,
+    /// Rocket's Secret Machine card
+    RocketsSecretMachine,
+    /// Trainer type not known by this version of the SDK, with its name as sent by the API
+    Unknown(String),
+}
+
+impl TrainerType {
+    /// Every known trainer type
+    pub const ALL: &'static [TrainerType] = &[
+        Self::Supporter,
+        Self::Item,
+        Self::Stadium,
+        Self::Tool,
+        Self::AceSpec,
+        Self::TechnicalMachine,
+        Self::GoldenrodGameCorner,
+        Self::RocketsSecretMachine,
+    ];
+}
+
+localized_enum!(TrainerType, "trainer type");
+
+/// English trainer type names, also used by languages without translation
+const TRAINER_TYPES_EN: [&str; 8] = [
+    "Supporter",
+    "Item",
+    "Stadium",
+    "Tool",
+    "Ace Spec",
+    "Technical Machine",
+    "Goldenrod Game Corner",
+    "Rocket's Secret Machine",
+];
+
+// The Asian tables only translate the main trainer types
+const TRAINER_TYPES_FR: [&str; 8] = [
+    "Supporter",
+    "Objet",
+    "Stade",
+    "Outil",
+    "High-tech",
+    "Machine Technique",
+    "Casino de Doublonville",
+    "Machine Secrète de Rocket",
+];
+const TRAINER_TYPES_ES: [&str; 8] = [
+    "Partidario",
+    "Objeto",
+    "Estadio",
+    "Herramienta",
+    "AS TÁCTICO",
+    "Máquina Técnica",
+    "Casino de Ciudad Trigal",
+    "Máquina Secreta de Rocket",
+];
+const TRAINER_TYPES_IT: [&str; 8] = [
+    "Aiuto",
+    "Strumento",
+    "Stadio",
+    "Oggetto Pokémon",
+    "ASSO TATTICO",
+    "Macchina Tecnica",
+    "Casinò di Fiordoropoli",
+    "Macchina Segreta del Team Rocket",
+];
+const TRAINER_TYPES_PT: [&str; 8] = [
+    "Apoiador",
+    "Item",
+    "Estádio",
+    "Ferramenta",
+    "ÁS TÁTICO",
+    "Máquina Técnica",
+    "Cassino de Goldenrod",
+    "Máquina Secreta da Equipe Rocket",
+];
+const TRAINER_TYPES_DE: [&str; 8] = [
+    "Unterstützer",
+    "Item",
+    "Stadion",
+    "Ausrüstung",
+    "ASS-SPEC",
+    "Technische Maschine",
+    "Spielhalle von Dukatia City",
+    "Geheimmaschine von Team Rocket",
+];
+const TRAINER_TYPES_NL: [&str; 8] = [
+    "Supporter",
+    "Item",
+    "Stadion",
+    "Tool",
+    "ACE SPEC",
+    "Technische Machine",
+    "Speelhal van Goldenrod",
+    "Geheime Machine van Team Rocket",
+];
+const TRAINER_TYPES_PL: [&str; 8] = [
+    "Wsparcie",
+    "Przedmiot",
+    "Stadion",
+    "Narzędzie",
+    "ACE SPEC",
+    "Maszyna Techniczna",
+    "Salon Gier w Goldenrod",
+    "Tajna Maszyna Rakiety",
+];
+const TRAINER_TYPES_RU: [&str; 8] = [
+    "Помощник",
+    "Предмет",
+    "Стадион",
+    "Инструмент",
+    "ACE SPEC",
+    "Техническая машина",
+    "Игровой зал Голденрода",
+    "Секретная машина Ракеты",
+];
+const TRAINER_TYPES_JA: [&str; 8] = [
+    "サポート",
+    "グッズ",
+    "スタジアム",
+    "ポケモンのどうぐ",
+    "Ace Spec",
+    "Technical Machine",
+    "Goldenrod Game Corner",
+    "Rocket's Secret Machine",
+];
+const TRAINER_TYPES_KO: [&str; 8] = [
+    "서포트",
+    "굿즈",
+    "스타디움",
+    "포켓몬의 도구",
+    "Ace Spec",
+    "Technical Machine",
+    "Goldenrod Game Corner",
+    "Rocket's Secret Machine",
+];
+const TRAINER_TYPES_ZH_TW: [&str; 8] = [
+    "支援者",
+    "物品",
+    "競技場",
+    "寶可夢道具",
+    "Ace Spec",
+    "Technical Machine",
+    "Goldenrod Game Corner",
+    "Rocket's Secret Machine",
+];
+const TRAINER_TYPES_ZH_CN: [&str; 8] = [
+    "支援者",
+    "物品",
+    "竞技场",
+    "宝可梦道具",
+    "Ace Spec",
+    "Technical Machine",
+    "Goldenrod Game Corner",
+    "Rocket's Secret Machine",
+];
+
+impl Localized for TrainerType {
+    const KNOWN: &'static [Self] = Self::ALL;
+
+    fn names(language: Language) -> &'static [&'static str] {
+        match language {
+            Language::FR => &TRAINER_TYPES_FR,
+            Language::ES | Language::ES_MX => &TRAINER_TYPES_ES,
+            Language::IT => &TRAINER_TYPES_IT,
+            Language::PT_BR | Language::PT_PT => &TRAINER_TYPES_PT,
+            Language::DE => &TRAINER_TYPES_DE,
+            Language::NL => &TRAINER_TYPES_NL,
+            Language::PL => &TRAINER_TYPES_PL,
+            Language::RU => &TRAINER_TYPES_RU,
+            Language::JA => &TRAINER_TYPES_JA,
+            Language::KO => &TRAINER_TYPES_KO,
+            Language::ZH_TW => &TRAINER_TYPES_ZH_TW,
+            Language::ZH_CN => &TRAINER_TYPES_ZH_CN,
+            _ => &TRAINER_TYPES_EN,
+        }
+    }
+
+    fn unknown(name: String) -> Self {
+        Self::Unknown(name)
+    }
+
+    fn unknown_name(&self) -> Option<&str> {
+        match self {
+            Self::Unknown(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+/// Sub-type of an Energy card
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum EnergyType {
+    /// Basic Energy card
+    Normal,
+    /// Special Energy card
+    Special,
+    /// Energy type not known by this version of the SDK, with its name as sent by the API
+    Unknown(String),
+}
+
+impl EnergyType {
+    /// Every known energy type
+    pub const ALL: &'static [EnergyType] = &[Self::Normal, Self::Special];
+}
+
+localized_enum!(EnergyType, "energy type");
+
+impl Localized for EnergyType {
+    const KNOWN: &'static [Self] = Self::ALL;
+
+    fn names(language: Language) -> &'static [&'static str] {
+        match language {
+            Language::FR => &["Normal", "Spécial"],
+            Language::ES | Language::ES_MX => &["Normal", "Especial"],
+            Language::IT => &["Base", "Speciale"],
+            Language::PT_BR | Language::PT_PT => &["Básica", "Especial"],
+            Language::DE => &["Basis", "Spezial"],
+            Language::JA | Language::ZH_TW | Language::ZH_CN => &["基本", "特殊"],
+            Language::KO => &["기본", "특수"],
+            _ => &["Normal", "Special"],
+        }
+    }
+
+    fn unknown(name: String) -> Self {
+        Self::Unknown(name)
+    }
+
+    fn unknown_name(&self) -> Option<&str> {
+        match self {
+            Self::Unknown(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+#[async_trait]
+impl Listable for EnergyType {
+    fn merge_key(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+impl Endpoint<StringEndpoint, EnergyType> {
+    /// Get the cards of an energy type, named in the language of the endpoint
+    pub async fn get_energy_type(&self, energy_type: &EnergyType) -> Result<StringEndpoint> {
+        self.get(energy_type.localized_name(self.language())).await
+    }
+}
+
+/// Suffix of a Pokémon name, identifying special Pokémon
+///
+/// Suffixes are the same in every language.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Suffix {
+    /// Pokémon-EX, written in uppercase
+    Ex,
+    /// Pokémon ex, written in lowercase
+    LowercaseEx,
+    /// Pokémon-GX
+    Gx,
+    /// TAG TEAM Pokémon-GX
+    TagTeamGx,
+    /// Pokémon V
+    V,
+    /// Pokémon VMAX
+    Vmax,
+    /// Pokémon VSTAR
+    Vstar,
+    /// Pokémon V-UNION
+    VUnion,
+    /// Pokémon LEGEND
+    Legend,
+    /// Pokémon Prime
+    Prime,
+    /// Pokémon SP
+    Sp,
+    /// Suffix not known by this version of the SDK, with its name as sent by the API
+    Unknown(String),
+}
+
+impl Suffix {
+    /// Every known suffix
+    pub const ALL: &'static [Suffix] = &[
+        Self::Ex,
+        Self::LowercaseEx,
+        Self::Gx,
+        Self::TagTeamGx,
+        Self::V,
+        Self::Vmax,
+        Self::Vstar,
+        Self::VUnion,
+        Self::Legend,
+        Self::Prime,
+        Self::Sp,
+    ];
+}
+
+localized_enum!(Suffix, "suffix");
+
+impl Localized for Suffix {
+    const KNOWN: &'static [Self] = Self::ALL;
+
+    fn names(_language: Language) -> &'static [&'static str] {
+        &[
+            "EX",
+            "ex",
+            "GX",
+            "TAG TEAM-GX",
+            "V",
+            "VMAX",
+            "VSTAR",
+            "V-UNION",
+            "Legend",
+            "Prime",
+            "SP",
+        ]
+    }
+
+    fn unknown(name: String) -> Self {
+        Self::Unknown(name)
+    }
+
+    fn unknown_name(&self) -> Option<&str> {
+        match self {
+            Self::Unknown(name) => Some(name),
+            _ => None,
+        }
+    }
+}
@@ -5,7 +5,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::endpoints::{Fetchable, Listable};
 use crate::error::Result;
-use crate::models::{Extension, Quality};
+use crate::models::{CardField, Extension, Quality};
+use crate::queryable::{values, FieldValue, Queryable};
 use crate::utils;
 
 /// Card resume class, contains basic information about a specific card
@@ -39,7 +40,7 @@ impl CardResume {
     ) -> Result<Option<bytes::Bytes>> {
         match self.get_image_url(quality, extension) {
             Some(url) => {
-                let bytes = utils::download_image(tcgdex.client(), &url).await?;
+                let bytes = utils::download_image(tcgdex.http(), &url).await?;
                 Ok(Some(bytes))
             }
             None => Ok(None),
@@ -59,5 +60,18 @@ impl Fetchable for CardResume {
 
 #[async_trait]
 impl Listable for CardResume {
-    // Using the default implementation
+    fn merge_key(&self) -> Option<String> {
+        Some(self.id.clone())
+    }
+}
+
+impl Queryable for CardResume {
+    fn field_values(&self, key: &str) -> Vec<FieldValue> {
+        match key.parse() {
+            Ok(CardField::Id) => values([self.id.as_str()]),
+            Ok(CardField::LocalId) => values([self.local_id.as_str()]),
+            Ok(CardField::Name) => values([self.name.as_str()]),
+            _ => Vec::new(),
+        }
+    }
 }
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::models::{DamageValue, EnergyCost, PokemonType};
+
 /// Card ability information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CardAbility {
@@ -14,20 +16,33 @@ pub struct CardAbility {
     pub effect: Option<String>,
 }
 
+/// Damage of an attack, as sent by the API
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum Damage {
+    /// Fixed damage
     Number(u16),
+    /// Damage changed by the attack effect, e.g. `30+`
     Formula(String),
 }
 
+impl Damage {
+    /// Parse the damage into its base and modifier, `None` for formulas without base damage
+    pub fn value(&self) -> Option<DamageValue> {
+        match self {
+            Self::Number(base) => Some(DamageValue::fixed(*base)),
+            Self::Formula(formula) => formula.parse().ok(),
+        }
+    }
+}
+
 /// Card attack information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CardAttack {
     /// Name of the attack
     pub name: Option<String>,
     /// Cost of the attack (energy types)
-    pub cost: Option<Vec<String>>,
+    pub cost: Option<Vec<PokemonType>>,
     /// Effect/Description of the attack
     pub effect: Option<String>,
     /// Damage the attack deals (numeric or formula)
@@ -35,6 +50,23 @@ pub struct CardAttack {
     pub damage: Option<Damage>,
 }
 
+impl CardAttack {
+    /// Get the parsed damage of the attack, `None` if it deals no damage
+    pub fn damage_value(&self) -> Option<DamageValue> {
+        self.damage.as_ref().and_then(Damage::value)
+    }
+
+    /// Get the Energy cost of the attack, counted by type
+    pub fn energy_cost(&self) -> EnergyCost {
+        EnergyCost::new(self.cost.as_deref().unwrap_or_default())
+    }
+
+    /// Check if the attached Energy, one type per Energy card, can pay the cost of the attack
+    pub fn can_pay(&self, energies: &[PokemonType]) -> bool {
+        self.energy_cost().can_pay(energies)
+    }
+}
+
 /// Card item information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CardItem {
@@ -61,12 +93,41 @@ pub struct CardVariants {
     pub w_promo: bool,
 }
 
+/// Detailed information about a card variant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardVariantDetailed {
+    /// The variant type (e.g., "normal", "holo", "reverse")
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The card size (e.g., "standard", "jumbo")
+    pub size: Option<String>,
+    /// The stamps printed on the card (e.g., "1st-edition", "set-logo")
+    pub stamp: Option<Vec<String>>,
+    /// The foil pattern of holographic and reverse variants
+    pub foil: Option<String>,
+}
+
+/// Booster pack information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Booster {
+    /// Globally unique booster ID
+    pub id: String,
+    /// The booster name
+    pub name: String,
+    /// The booster logo URL
+    pub logo: Option<String>,
+    /// The booster front artwork URL
+    pub artwork_front: Option<String>,
+    /// The booster back artwork URL
+    pub artwork_back: Option<String>,
+}
+
 /// Card weakness/resistance information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CardWeakRes {
     /// The affecting energy type
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: PokemonType,
     /// The multiplier/value (e.g., "x2", "-30")
     pub value: Option<String>,
 }
@@ -98,6 +159,22 @@ pub struct SetCardCount {
     pub first_ed: Option<u16>,
 }
 
+impl SetCardCount {
+    /// Get the number of secret rares, the cards numbered beyond the official count
+    pub fn secret_rare_count(&self) -> u16 {
+        self.total.saturating_sub(self.official)
+    }
+
+    /// Get the number of cards needed for a master set, one of each variant of each card
+    ///
+    /// `None` unless the API sent every variant count, since a missing count
+    /// cannot be told apart from a variant the set does not have.
+    pub fn master_set_total(&self) -> Option<u32> {
+        let variants = [self.normal, self.reverse, self.holo, self.first_ed];
+        variants.iter().map(|count| count.map(u32::from)).sum()
+    }
+}
+
 /// Set card count summary
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SetCardCountResume {
@@ -0,0 +1,333 @@
+//! Dates and timestamps sent by the API
+//!
+//! They are parsed by hand so that the SDK needs no date library: only the
+//! `yyyy-mm-dd` dates and RFC 3339 timestamps sent by the API are accepted.
+//! With the `chrono` feature, both convert to and from the `chrono` types.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A calendar date, sent by the API as `yyyy-mm-dd`
+///
+/// Dates are ordered chronologically and display in the API format, so they
+/// can be given to the [`Query`](crate::Query) comparison methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+/// A point in time with its UTC offset, sent by the API as RFC 3339,
+/// e.g. `2025-08-15T21:42:19+02:00`
+///
+/// Timestamps are compared by the instant they represent, whatever their offset.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Timestamp {
+    date: Date,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+    offset_minutes: i16,
+}
+
+/// Error returned when a text, or a `chrono` date, is not a valid [`Date`] or [`Timestamp`]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid date: {text}")]
+pub struct ParseDateError {
+    text: String,
+}
+
+impl ParseDateError {
+    fn new(text: impl fmt::Display) -> Self {
+        Self {
+            text: text.to_string(),
+        }
+    }
+}
+
+impl Date {
+    /// Create a date, `None` if the day does not exist or the year is not between 0 and 9999
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        let valid = (0..=9999).contains(&year)
+            && (1..=12).contains(&month)
+            && day >= 1
+            && day <= days_in_month(year, month);
+        valid.then_some(Self { year, month, day })
+    }
+
+    /// Get the year
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Get the month, from 1 to 12
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Get the day of the month, from 1 to 31
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    // Count the days since 1970-01-01, negative before
+    fn days_since_epoch(&self) -> i64 {
+        // Days from civil, counting years from March so leap days come last
+        let month = i64::from(self.month);
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+}
+
+impl Timestamp {
+    /// Get the date, in the timestamp offset
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// Get the hour, from 0 to 23
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Get the minute, from 0 to 59
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Get the second, from 0 to 59
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Get the fraction of the second, in nanoseconds
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    /// Get the offset from UTC, in minutes
+    pub fn offset_minutes(&self) -> i16 {
+        self.offset_minutes
+    }
+
+    /// Get the number of seconds since 1970-01-01T00:00:00Z
+    pub fn unix_timestamp(&self) -> i64 {
+        let time = i64::from(self.hour) * 3600 + i64::from(self.minute) * 60;
+        self.date.days_since_epoch() * 86_400 + time + i64::from(self.second)
+            - i64::from(self.offset_minutes) * 60
+    }
+
+    // Parse an RFC 3339 timestamp
+    fn parse(text: &str) -> Option<Self> {
+        let date = Date::from_str(text.get(..10)?).ok()?;
+        let rest = text.get(10..)?.strip_prefix(['T', 't', ' '])?;
+
+        let offset_start = rest.find(['Z', 'z', '+', '-'])?;
+        let (time, offset) = rest.split_at(offset_start);
+        let (time, fraction) = time.split_once('.').unwrap_or((time, ""));
+        let [hour, minute, second] = digit_fields(time, ':', &[2, 2, 2])?;
+
+        if fraction.len() > 9 || !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+        let nanosecond = match fraction {
+            "" => 0,
+            _ => format!("{:0<9}", fraction).parse().ok()?,
+        };
+
+        let offset_minutes = match offset {
+            "Z" | "z" => 0,
+            _ => {
+                let sign = if offset.starts_with('-') { -1 } else { 1 };
+                let [hours, minutes] = digit_fields(&offset[1..], ':', &[2, 2])?;
+                if hours > 23 || minutes > 59 {
+                    return None;
+                }
+                sign * (hours as i16 * 60 + minutes as i16)
+            }
+        };
+
+        (hour < 24 && minute < 60 && second < 60).then_some(Self {
+            date,
+            hour: hour as u8,
+            minute: minute as u8,
+            second: second as u8,
+            nanosecond,
+            offset_minutes,
+        })
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}T{:02}:{:02}:{:02}",
+            self.date, self.hour, self.minute, self.second
+        )?;
+        if self.nanosecond > 0 {
+            let fraction = format!("{:09}", self.nanosecond);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        match self.offset_minutes {
+            0 => f.write_str("Z"),
+            offset => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.unsigned_abs();
+                write!(f, "{}{:02}:{:02}", sign, offset / 60, offset % 60)
+            }
+        }
+    }
+}
+
+impl FromStr for Date {
+    type Err = ParseDateError;
+
+    fn from_str(text: &str) -> Result<Self, ParseDateError> {
+        let invalid = || ParseDateError::new(text);
+        let [year, month, day] = digit_fields(text, '-', &[4, 2, 2]).ok_or_else(invalid)?;
+        Self::new(year as i32, month as u8, day as u8).ok_or_else(invalid)
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = ParseDateError;
+
+    fn from_str(text: &str) -> Result<Self, ParseDateError> {
+        Self::parse(text).ok_or_else(|| ParseDateError::new(text))
+    }
+}
+
+impl TryFrom<String> for Date {
+    type Error = ParseDateError;
+
+    fn try_from(text: String) -> Result<Self, ParseDateError> {
+        text.parse()
+    }
+}
+
+impl TryFrom<String> for Timestamp {
+    type Error = ParseDateError;
+
+    fn try_from(text: String) -> Result<Self, ParseDateError> {
+        text.parse()
+    }
+}
+
+impl From<Date> for String {
+    fn from(date: Date) -> Self {
+        date.to_string()
+    }
+}
+
+impl From<Timestamp> for String {
+    fn from(timestamp: Timestamp) -> Self {
+        timestamp.to_string()
+    }
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.unix_timestamp(), self.nanosecond).hash(state);
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.unix_timestamp(), self.nanosecond).cmp(&(other.unix_timestamp(), other.nanosecond))
+    }
+}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Date> for chrono::NaiveDate {
+    fn from(date: Date) -> Self {
+        chrono::NaiveDate::from_ymd_opt(date.year, date.month.into(), date.day.into())
+            .expect("dates are validated when created")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::NaiveDate> for Date {
+    type Error = ParseDateError;
+
+    /// Convert a `chrono` date, failing outside of the years 0 to 9999
+    fn try_from(date: chrono::NaiveDate) -> Result<Self, ParseDateError> {
+        use chrono::Datelike;
+
+        Self::new(date.year(), date.month() as u8, date.day() as u8)
+            .ok_or_else(|| ParseDateError::new(date))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Timestamp> for chrono::DateTime<chrono::FixedOffset> {
+    fn from(timestamp: Timestamp) -> Self {
+        let offset = chrono::FixedOffset::east_opt(i32::from(timestamp.offset_minutes) * 60)
+            .expect("offsets are validated when parsed");
+        let utc =
+            chrono::DateTime::from_timestamp(timestamp.unix_timestamp(), timestamp.nanosecond)
+                .expect("timestamps are validated when parsed");
+        utc.with_timezone(&offset)
+    }
+}
+
+// Parse fields of digits with exactly these lengths, separated by the separator
+fn digit_fields<const N: usize>(
+    text: &str,
+    separator: char,
+    lengths: &[usize; N],
+) -> Option<[u32; N]> {
+    let mut fields = [0; N];
+    let mut parts = text.split(separator);
+    for (field, length) in fields.iter_mut().zip(lengths) {
+        let part = parts.next()?;
+        if part.len() != *length || !part.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+        *field = part.parse().ok()?;
+    }
+    parts.next().is_none().then_some(fields)
+}
+
+// Count the days of a month, accounting for leap years
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
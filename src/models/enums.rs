@@ -3,11 +3,12 @@
 use strum_macros::{Display, EnumString};
 
 /// Language codes supported by the TCGdex API
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumString)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Display, EnumString)]
 #[strum(serialize_all = "lowercase")]
 #[allow(non_camel_case_types)]
 pub enum Language {
     /// English
+    #[default]
     EN,
     /// French
     FR,
@@ -48,17 +49,12 @@ pub enum Language {
     ZH_CN,
 }
 
-impl Default for Language {
-    fn default() -> Self {
-        Self::EN
-    }
-}
-
 /// The different image formats available
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumString)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Display, EnumString)]
 #[strum(serialize_all = "lowercase")]
 pub enum Extension {
     /// PNG image with transparent background
+    #[default]
     PNG,
     /// JPG image with white background
     JPG,
@@ -66,24 +62,13 @@ pub enum Extension {
     WEBP,
 }
 
-impl Default for Extension {
-    fn default() -> Self {
-        Self::PNG
-    }
-}
-
 /// Image quality options
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumString)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Display, EnumString)]
 #[strum(serialize_all = "lowercase")]
 pub enum Quality {
     /// High quality image
+    #[default]
     HIGH,
     /// Low quality image
     LOW,
 }
-
-impl Default for Quality {
-    fn default() -> Self {
-        Self::HIGH
-    }
-}
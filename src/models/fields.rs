@@ -0,0 +1,202 @@
+//! Fields that can be used to filter and sort API lists
+//!
+//! Each field displays as the name the API expects, so they can be given to
+//! any [`Query`](crate::Query) method instead of a string.
+
+use strum_macros::{AsRefStr, Display, EnumString};
+
+/// Card fields usable in a query on the cards endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumString, AsRefStr)]
+pub enum CardField {
+    /// Globally unique card ID
+    #[strum(serialize = "id")]
+    Id,
+    /// ID indexing the card within its set
+    #[strum(serialize = "localId")]
+    LocalId,
+    /// Card name
+    #[strum(serialize = "name")]
+    Name,
+    /// Card category (Pokemon, Trainer or Energy)
+    #[strum(serialize = "category")]
+    Category,
+    /// Card illustrator
+    #[strum(serialize = "illustrator")]
+    Illustrator,
+    /// Card rarity
+    #[strum(serialize = "rarity")]
+    Rarity,
+    /// ID of the set the card belongs to
+    #[strum(serialize = "set.id")]
+    SetId,
+    /// Name of the set the card belongs to
+    #[strum(serialize = "set.name")]
+    SetName,
+    /// Pokédex IDs of the Pokémon
+    #[strum(serialize = "dexId")]
+    DexId,
+    /// HP of the Pokémon
+    #[strum(serialize = "hp")]
+    Hp,
+    /// Types of the Pokémon
+    #[strum(serialize = "types")]
+    Types,
+    /// Name of the Pokémon this one evolves from
+    #[strum(serialize = "evolveFrom")]
+    EvolveFrom,
+    /// Pokédex description of the Pokémon
+    #[strum(serialize = "description")]
+    Description,
+    /// Level of the Pokémon
+    #[strum(serialize = "level")]
+    Level,
+    /// Evolution stage of the Pokémon
+    #[strum(serialize = "stage")]
+    Stage,
+    /// Suffix of the Pokémon
+    #[strum(serialize = "suffix")]
+    Suffix,
+    /// Name of the item the Pokémon has
+    #[strum(serialize = "item.name")]
+    ItemName,
+    /// Names of the card abilities
+    #[strum(serialize = "abilities.name")]
+    AbilityName,
+    /// Names of the card attacks
+    #[strum(serialize = "attacks.name")]
+    AttackName,
+    /// Types of the Pokémon weaknesses
+    #[strum(serialize = "weaknesses.type")]
+    WeaknessType,
+    /// Types of the Pokémon resistances
+    #[strum(serialize = "resistances.type")]
+    ResistanceType,
+    /// Retreat cost of the Pokémon
+    #[strum(serialize = "retreat")]
+    Retreat,
+    /// Effect of a Trainer or Energy card
+    #[strum(serialize = "effect")]
+    Effect,
+    /// Trainer sub-type
+    #[strum(serialize = "trainerType")]
+    TrainerType,
+    /// Energy sub-type
+    #[strum(serialize = "energyType")]
+    EnergyType,
+    /// Regulation mark
+    #[strum(serialize = "regulationMark")]
+    RegulationMark,
+    /// Whether the card is usable in the standard format
+    #[strum(serialize = "legal.standard")]
+    LegalStandard,
+    /// Whether the card is usable in the expanded format
+    #[strum(serialize = "legal.expanded")]
+    LegalExpanded,
+    /// IDs of the boosters the card can be found in
+    #[strum(serialize = "boosters.id")]
+    BoosterId,
+    /// Average sale price on Cardmarket
+    #[strum(serialize = "pricing.cardmarket.avg")]
+    CardmarketAverage,
+    /// Lowest listed price on Cardmarket
+    #[strum(serialize = "pricing.cardmarket.low")]
+    CardmarketLow,
+    /// Price trend on Cardmarket
+    #[strum(serialize = "pricing.cardmarket.trend")]
+    CardmarketTrend,
+    /// Market price of the normal variant on TCGplayer
+    #[strum(serialize = "pricing.tcgplayer.normal.marketPrice")]
+    TcgplayerNormalMarket,
+    /// Market price of the holofoil variant on TCGplayer
+    #[strum(serialize = "pricing.tcgplayer.holofoil.marketPrice")]
+    TcgplayerHolofoilMarket,
+    /// Market price of the reverse holofoil variant on TCGplayer
+    #[strum(serialize = "pricing.tcgplayer.reverse-holofoil.marketPrice")]
+    TcgplayerReverseHolofoilMarket,
+}
+
+/// Card price fields, usable in the price filters of a
+/// [`CardQuery`](crate::CardQuery)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumString, AsRefStr)]
+pub enum PriceField {
+    /// Average sale price on Cardmarket
+    #[strum(serialize = "pricing.cardmarket.avg")]
+    CardmarketAverage,
+    /// Lowest listed price on Cardmarket
+    #[strum(serialize = "pricing.cardmarket.low")]
+    CardmarketLow,
+    /// Price trend on Cardmarket
+    #[strum(serialize = "pricing.cardmarket.trend")]
+    CardmarketTrend,
+    /// Market price of the normal variant on TCGplayer
+    #[strum(serialize = "pricing.tcgplayer.normal.marketPrice")]
+    TcgplayerNormalMarket,
+    /// Market price of the holofoil variant on TCGplayer
+    #[strum(serialize = "pricing.tcgplayer.holofoil.marketPrice")]
+    TcgplayerHolofoilMarket,
+    /// Market price of the reverse holofoil variant on TCGplayer
+    #[strum(serialize = "pricing.tcgplayer.reverse-holofoil.marketPrice")]
+    TcgplayerReverseHolofoilMarket,
+}
+
+impl From<PriceField> for CardField {
+    fn from(field: PriceField) -> Self {
+        match field {
+            PriceField::CardmarketAverage => Self::CardmarketAverage,
+            PriceField::CardmarketLow => Self::CardmarketLow,
+            PriceField::CardmarketTrend => Self::CardmarketTrend,
+            PriceField::TcgplayerNormalMarket => Self::TcgplayerNormalMarket,
+            PriceField::TcgplayerHolofoilMarket => Self::TcgplayerHolofoilMarket,
+            PriceField::TcgplayerReverseHolofoilMarket => Self::TcgplayerReverseHolofoilMarket,
+        }
+    }
+}
+
+/// Set fields usable in a query on the sets endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumString, AsRefStr)]
+pub enum SetField {
+    /// Globally unique set ID
+    #[strum(serialize = "id")]
+    Id,
+    /// Set name
+    #[strum(serialize = "name")]
+    Name,
+    /// ID of the serie the set belongs to
+    #[strum(serialize = "serie.id")]
+    SerieId,
+    /// Name of the serie the set belongs to
+    #[strum(serialize = "serie.name")]
+    SerieName,
+    /// Pokémon TCG Online code
+    #[strum(serialize = "tcgOnline")]
+    TcgOnline,
+    /// Release date as yyyy-mm-dd, ordered chronologically by comparisons
+    #[strum(serialize = "releaseDate")]
+    ReleaseDate,
+    /// Total number of cards
+    #[strum(serialize = "cardCount.total")]
+    CardCountTotal,
+    /// Number of cards officially counted by the set
+    #[strum(serialize = "cardCount.official")]
+    CardCountOfficial,
+    /// Whether the set is usable in the standard format
+    #[strum(serialize = "legal.standard")]
+    LegalStandard,
+    /// Whether the set is usable in the expanded format
+    #[strum(serialize = "legal.expanded")]
+    LegalExpanded,
+}
+
+/// Serie fields usable in a query on the series endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumString, AsRefStr)]
+pub enum SerieField {
+    /// Unique serie ID
+    #[strum(serialize = "id")]
+    Id,
+    /// Serie name
+    #[strum(serialize = "name")]
+    Name,
+    /// Release date of the first set as yyyy-mm-dd, ordered chronologically by comparisons
+    #[strum(serialize = "releaseDate")]
+    ReleaseDate,
+}
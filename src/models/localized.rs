@@ -0,0 +1,157 @@
+//! Shared handling of values whose names depend on the language
+
+use crate::models::Language;
+
+/// A value with a known name in every language, or an unknown name
+///
+/// Implemented by the enums of localized card values, which expose these
+/// functions with their own documentation.
+pub(crate) trait Localized: Sized + Clone + PartialEq + 'static {
+    /// Known values, in the order of the name tables
+    const KNOWN: &'static [Self];
+
+    /// Get the names of the known values in the language, in the order of [`Self::KNOWN`]
+    fn names(language: Language) -> &'static [&'static str];
+
+    /// Wrap a name matching no known value
+    fn unknown(name: String) -> Self;
+
+    /// Get the name of an unknown value, `None` for known values
+    fn unknown_name(&self) -> Option<&str>;
+
+    /// Get the name of the value in the language
+    fn localized_name(&self, language: Language) -> &str {
+        match self.unknown_name() {
+            Some(name) => name,
+            None => {
+                let index = Self::KNOWN.iter().position(|known| known == self);
+                index.map_or("", |index| Self::names(language)[index])
+            }
+        }
+    }
+
+    /// Get the names of the value in every language, English first and without duplicates
+    fn all_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for language in LANGUAGES {
+            let name = self.localized_name(*language);
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    /// Parse a name in the language, ignoring case
+    fn parse_localized(name: &str, language: Language) -> Self {
+        Self::find(name, language).unwrap_or_else(|| Self::unknown(name.to_string()))
+    }
+
+    /// Parse a name in any language, ignoring case
+    fn parse_any(name: &str) -> Self {
+        LANGUAGES
+            .iter()
+            .find_map(|language| Self::find(name, *language))
+            .unwrap_or_else(|| Self::unknown(name.to_string()))
+    }
+
+    // Find the known value with the name in the language, preferring names
+    // with the same case since some only differ by case, e.g. `EX` and `ex`
+    fn find(name: &str, language: Language) -> Option<Self> {
+        let names = Self::names(language);
+        let name = name.trim();
+        let lowercase = name.to_lowercase();
+        names
+            .iter()
+            .position(|candidate| *candidate == name)
+            .or_else(|| {
+                names
+                    .iter()
+                    .position(|candidate| candidate.to_lowercase() == lowercase)
+            })
+            .map(|index| Self::KNOWN[index].clone())
+    }
+}
+
+/// Every language, English first since it is the most used
+pub(crate) const LANGUAGES: &[Language] = &[
+    Language::EN,
+    Language::FR,
+    Language::ES,
+    Language::ES_MX,
+    Language::IT,
+    Language::PT_BR,
+    Language::PT_PT,
+    Language::DE,
+    Language::NL,
+    Language::PL,
+    Language::RU,
+    Language::JA,
+    Language::KO,
+    Language::ZH_TW,
+    Language::ID,
+    Language::TH,
+    Language::ZH_CN,
+];
+
+/// Implement the public API of a [`Localized`] enum with an `Unknown(String)`
+/// variant: localized names, parsing, English display and string conversions
+macro_rules! localized_enum {
+    ($name:ident, $what:literal) => {
+        impl $name {
+            #[doc = concat!("Get the name of the ", $what, " in the language, as used by the API")]
+            pub fn localized_name(&self, language: $crate::models::Language) -> &str {
+                $crate::models::localized::Localized::localized_name(self, language)
+            }
+
+            #[doc = concat!("Parse a ", $what, " name in the language, ignoring case")]
+            pub fn parse_localized(name: &str, language: $crate::models::Language) -> Self {
+                $crate::models::localized::Localized::parse_localized(name, language)
+            }
+
+            #[doc = concat!("Check if the ", $what, " is known by this version of the SDK")]
+            pub fn is_known(&self) -> bool {
+                $crate::models::localized::Localized::unknown_name(self).is_none()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            /// Write the English name
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.localized_name($crate::models::Language::EN))
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            /// Parse a name in any language, unknown names giving the `Unknown` variant
+            fn from_str(name: &str) -> std::result::Result<Self, Self::Err> {
+                Ok($crate::models::localized::Localized::parse_any(name))
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(name: &str) -> Self {
+                $crate::models::localized::Localized::parse_any(name)
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(name: String) -> Self {
+                $crate::models::localized::Localized::parse_any(&name)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                match $crate::models::localized::Localized::unknown_name(&value) {
+                    Some(name) => name.to_string(),
+                    None => value.to_string(),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use localized_enum;
@@ -1,10 +1,17 @@
 //! Data models for the TCGdex API
 
+mod attack;
 mod card;
+mod card_enums;
 mod card_resume;
 mod common;
+mod date;
 pub mod enums;
+mod fields;
 mod int_endpoint;
+mod localized;
+mod pokemon_type;
+mod pricing;
 mod primitive_lists;
 mod serie;
 mod serie_resume;
@@ -12,9 +19,18 @@ mod set;
 mod set_resume;
 mod string_endpoint;
 
+pub use self::attack::{DamageModifier, DamageValue, EnergyCost, ParseDamageError};
 pub use self::card::Card;
+pub use self::card_enums::{Category, EnergyType, Rarity, Stage, Suffix, TrainerType};
 pub use self::card_resume::CardResume;
+pub use self::date::{Date, ParseDateError, Timestamp};
+pub use self::fields::{CardField, PriceField, SerieField, SetField};
 pub use self::int_endpoint::IntEndpoint;
+pub(crate) use self::localized::Localized;
+pub use self::pokemon_type::PokemonType;
+pub use self::pricing::{
+    CardPricing, CardmarketPricing, TcgplayerPricing, TcgplayerVariantPricing,
+};
 pub use self::primitive_lists::{IntList, StringList};
 pub use self::serie::Serie;
 pub use self::serie_resume::SerieResume;
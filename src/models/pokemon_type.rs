@@ -0,0 +1,271 @@
+//! Pokémon type, shared by cards, attack costs, weaknesses and resistances
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::endpoints::{Endpoint, Listable};
+use crate::error::Result;
+use crate::models::localized::{localized_enum, Localized};
+use crate::models::{Language, StringEndpoint};
+
+/// Type of a Pokémon or of an Energy
+///
+/// The API names types in the requested language, e.g. `Fire`, `Feu` or
+/// `Feuer`, which are all parsed to [`PokemonType::Fire`]. Types are
+/// serialized with their English name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum PokemonType {
+    /// Grass type
+    Grass,
+    /// Fire type
+    Fire,
+    /// Water type
+    Water,
+    /// Lightning type
+    Lightning,
+    /// Psychic type
+    Psychic,
+    /// Fighting type
+    Fighting,
+    /// Darkness type
+    Darkness,
+    /// Metal type
+    Metal,
+    /// Fairy type
+    Fairy,
+    /// Dragon type
+    Dragon,
+    /// Colorless type
+    Colorless,
+    /// Type not known by this version of the SDK, with its name as sent by the API
+    Unknown(String),
+}
+
+impl PokemonType {
+    /// Every known type
+    pub const ALL: &'static [PokemonType] = &[
+        Self::Grass,
+        Self::Fire,
+        Self::Water,
+        Self::Lightning,
+        Self::Psychic,
+        Self::Fighting,
+        Self::Darkness,
+        Self::Metal,
+        Self::Fairy,
+        Self::Dragon,
+        Self::Colorless,
+    ];
+}
+
+localized_enum!(PokemonType, "type");
+
+impl Localized for PokemonType {
+    const KNOWN: &'static [Self] = Self::ALL;
+
+    fn names(language: Language) -> &'static [&'static str] {
+        match language {
+            Language::EN => &[
+                "Grass",
+                "Fire",
+                "Water",
+                "Lightning",
+                "Psychic",
+                "Fighting",
+                "Darkness",
+                "Metal",
+                "Fairy",
+                "Dragon",
+                "Colorless",
+            ],
+            Language::FR => &[
+                "Plante",
+                "Feu",
+                "Eau",
+                "Électrique",
+                "Psy",
+                "Combat",
+                "Obscurité",
+                "Métal",
+                "Fée",
+                "Dragon",
+                "Incolore",
+            ],
+            Language::ES | Language::ES_MX => &[
+                "Planta",
+                "Fuego",
+                "Agua",
+                "Rayo",
+                "Psíquico",
+                "Lucha",
+                "Oscuro",
+                "Metálico",
+                "Hada",
+                "Dragón",
+                "Incoloro",
+            ],
+            Language::IT => &[
+                "Erba",
+                "Fuoco",
+                "Acqua",
+                "Lampo",
+                "Psico",
+                "Lotta",
+                "Oscurità",
+                "Metallo",
+                "Folletto",
+                "Drago",
+                "Incolore",
+            ],
+            Language::PT_BR | Language::PT_PT => &[
+                "Planta",
+                "Fogo",
+                "Água",
+                "Elétrico",
+                "Psíquico",
+                "Luta",
+                "Escuridão",
+                "Metal",
+                "Fada",
+                "Dragão",
+                "Incolor",
+            ],
+            Language::DE => &[
+                "Pflanze",
+                "Feuer",
+                "Wasser",
+                "Elektro",
+                "Psycho",
+                "Kampf",
+                "Finsternis",
+                "Metall",
+                "Fee",
+                "Drache",
+                "Farblos",
+            ],
+            Language::NL => &[
+                "Gras",
+                "Vuur",
+                "Water",
+                "Bliksem",
+                "Psychisch",
+                "Vechten",
+                "Duisternis",
+                "Metaal",
+                "Fee",
+                "Draak",
+                "Kleurloos",
+            ],
+            Language::PL => &[
+                "Trawa",
+                "Ogień",
+                "Woda",
+                "Piorun",
+                "Psychiczny",
+                "Walka",
+                "Ciemność",
+                "Metal",
+                "Wróżka",
+                "Smok",
+                "Bezbarwny",
+            ],
+            Language::RU => &[
+                "Трава",
+                "Огонь",
+                "Вода",
+                "Молния",
+                "Психический",
+                "Борьба",
+                "Тьма",
+                "Металл",
+                "Фея",
+                "Дракон",
+                "Бесцветный",
+            ],
+            Language::JA => &[
+                "草",
+                "炎",
+                "水",
+                "雷",
+                "超",
+                "闘",
+                "悪",
+                "鋼",
+                "フェアリー",
+                "ドラゴン",
+                "無色",
+            ],
+            Language::KO => &[
+                "풀",
+                "불꽃",
+                "물",
+                "번개",
+                "초",
+                "격투",
+                "악",
+                "강철",
+                "페어리",
+                "드래곤",
+                "무색",
+            ],
+            Language::ZH_TW => &[
+                "草", "火", "水", "雷", "超", "鬥", "惡", "鋼", "妖精", "龍", "無色",
+            ],
+            Language::ZH_CN => &[
+                "草", "火", "水", "雷", "超", "斗", "恶", "钢", "妖精", "龙", "无色",
+            ],
+            Language::ID => &[
+                "Rumput",
+                "Api",
+                "Air",
+                "Listrik",
+                "Psikis",
+                "Petarung",
+                "Kegelapan",
+                "Logam",
+                "Peri",
+                "Naga",
+                "Tak Berwarna",
+            ],
+            Language::TH => &[
+                "หญ้า",
+                "ไฟ",
+                "น้ำ",
+                "สายฟ้า",
+                "พลังจิต",
+                "ต่อสู้",
+                "ความมืด",
+                "โลหะ",
+                "แฟรี่",
+                "มังกร",
+                "ไร้สี",
+            ],
+        }
+    }
+
+    fn unknown(name: String) -> Self {
+        Self::Unknown(name)
+    }
+
+    fn unknown_name(&self) -> Option<&str> {
+        match self {
+            Self::Unknown(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+#[async_trait]
+impl Listable for PokemonType {
+    fn merge_key(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+impl Endpoint<StringEndpoint, PokemonType> {
+    /// Get the cards of a type, named in the language of the endpoint
+    pub async fn get_type(&self, type_: &PokemonType) -> Result<StringEndpoint> {
+        self.get(type_.localized_name(self.language())).await
+    }
+}
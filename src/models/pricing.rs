@@ -0,0 +1,119 @@
+//! Card prices from the Cardmarket and TCGplayer marketplaces
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::Timestamp;
+
+/// Card prices, by marketplace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardPricing {
+    /// Prices on Cardmarket, mostly European sales
+    pub cardmarket: Option<CardmarketPricing>,
+    /// Prices on TCGplayer, mostly American sales
+    pub tcgplayer: Option<TcgplayerPricing>,
+}
+
+/// Card prices on Cardmarket
+///
+/// Averages are computed on the sales of the last days. The `_holo` prices
+/// are for the holographic or reverse variants of the card.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardmarketPricing {
+    /// Date and time of the last update, `None` if missing or malformed
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_lenient_timestamp"
+    )]
+    pub updated: Option<Timestamp>,
+    /// Currency of the prices, e.g. `EUR`
+    #[serde(rename = "unit")]
+    pub currency: Option<String>,
+    /// Cardmarket product ID
+    #[serde(rename = "idProduct")]
+    pub product_id: Option<u64>,
+    /// Average sale price
+    pub avg: Option<f64>,
+    /// Lowest listed price
+    pub low: Option<f64>,
+    /// Price trend
+    pub trend: Option<f64>,
+    /// Average sale price of the last day
+    pub avg1: Option<f64>,
+    /// Average sale price of the last 7 days
+    pub avg7: Option<f64>,
+    /// Average sale price of the last 30 days
+    pub avg30: Option<f64>,
+    /// Average sale price of the holographic variant
+    #[serde(rename = "avg-holo")]
+    pub avg_holo: Option<f64>,
+    /// Lowest listed price of the holographic variant
+    #[serde(rename = "low-holo")]
+    pub low_holo: Option<f64>,
+    /// Price trend of the holographic variant
+    #[serde(rename = "trend-holo")]
+    pub trend_holo: Option<f64>,
+    /// Average sale price of the holographic variant of the last day
+    #[serde(rename = "avg1-holo")]
+    pub avg1_holo: Option<f64>,
+    /// Average sale price of the holographic variant of the last 7 days
+    #[serde(rename = "avg7-holo")]
+    pub avg7_holo: Option<f64>,
+    /// Average sale price of the holographic variant of the last 30 days
+    #[serde(rename = "avg30-holo")]
+    pub avg30_holo: Option<f64>,
+}
+
+/// Card prices on TCGplayer, by variant
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TcgplayerPricing {
+    /// Date and time of the last update, `None` if missing or malformed
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_lenient_timestamp"
+    )]
+    pub updated: Option<Timestamp>,
+    /// Currency of the prices, e.g. `USD`
+    #[serde(rename = "unit")]
+    pub currency: Option<String>,
+    /// Prices of the normal variant
+    pub normal: Option<TcgplayerVariantPricing>,
+    /// Prices of the holofoil variant
+    pub holofoil: Option<TcgplayerVariantPricing>,
+    /// Prices of the reverse holofoil variant
+    #[serde(rename = "reverse-holofoil")]
+    pub reverse_holofoil: Option<TcgplayerVariantPricing>,
+    /// Prices of the first edition variant
+    #[serde(rename = "1st-edition")]
+    pub first_edition: Option<TcgplayerVariantPricing>,
+    /// Prices of the first edition holofoil variant
+    #[serde(rename = "1st-edition-holofoil")]
+    pub first_edition_holofoil: Option<TcgplayerVariantPricing>,
+    /// Prices of the unlimited variant
+    pub unlimited: Option<TcgplayerVariantPricing>,
+    /// Prices of the unlimited holofoil variant
+    #[serde(rename = "unlimited-holofoil")]
+    pub unlimited_holofoil: Option<TcgplayerVariantPricing>,
+}
+
+/// Prices of a card variant on TCGplayer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TcgplayerVariantPricing {
+    /// TCGplayer product ID
+    #[serde(rename = "productId")]
+    pub product_id: Option<u64>,
+    /// Lowest listed price
+    #[serde(rename = "lowPrice")]
+    pub low_price: Option<f64>,
+    /// Median listed price
+    #[serde(rename = "midPrice")]
+    pub mid_price: Option<f64>,
+    /// Highest listed price
+    #[serde(rename = "highPrice")]
+    pub high_price: Option<f64>,
+    /// Market price, based on recent sales
+    #[serde(rename = "marketPrice")]
+    pub market_price: Option<f64>,
+    /// Lowest price of TCGplayer Direct sellers
+    #[serde(rename = "directLowPrice")]
+    pub direct_low_price: Option<f64>,
+}
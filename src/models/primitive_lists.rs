@@ -163,7 +163,16 @@ impl IntList {
 
 // Implement Listable for our wrapper types
 #[async_trait]
-impl Listable for StringList {}
+impl Listable for StringList {
+    fn merge_key(&self) -> Option<String> {
+        Some(self.0.join("|"))
+    }
+}
 
 #[async_trait]
-impl Listable for IntList {}
+impl Listable for IntList {
+    fn merge_key(&self) -> Option<String> {
+        let values: Vec<String> = self.0.iter().map(ToString::to_string).collect();
+        Some(values.join("|"))
+    }
+}
@@ -5,7 +5,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::endpoints::{Fetchable, Listable};
 use crate::error::Result;
-use crate::models::{Extension, SetResume};
+use crate::models::{Date, Extension, SerieField, Set, SetResume};
+use crate::queryable::{values, FieldValue, Queryable};
 use crate::utils;
 
 /// Pokémon TCG Serie, contains all information about a specific serie
@@ -19,6 +20,19 @@ pub struct Serie {
     pub name: String,
     /// The serie logo URL (without extension)
     pub logo: Option<String>,
+    /// The release date of the first set of the serie, `None` if missing or malformed
+    #[serde(
+        rename = "releaseDate",
+        default,
+        deserialize_with = "crate::utils::deserialize_lenient_date"
+    )]
+    pub release_date: Option<Date>,
+    /// The first set released in the serie
+    #[serde(rename = "firstSet")]
+    pub first_set: Option<SetResume>,
+    /// The last set released in the serie
+    #[serde(rename = "lastSet")]
+    pub last_set: Option<SetResume>,
 }
 
 impl Serie {
@@ -37,12 +51,23 @@ impl Serie {
     ) -> Result<Option<bytes::Bytes>> {
         match self.get_logo_url(extension) {
             Some(url) => {
-                let bytes = utils::download_image(tcgdex.client(), &url).await?;
+                let bytes = utils::download_image(tcgdex.http(), &url).await?;
                 Ok(Some(bytes))
             }
             None => Ok(None),
         }
     }
+
+    /// Fetch the full information of every set of the serie, in the serie order
+    ///
+    /// Each set has its own result, so a missing set does not prevent getting the others.
+    pub async fn fetch_all_sets(&self, tcgdex: &crate::TCGdex) -> Vec<Result<Set>> {
+        let ids = self.sets.iter().map(|set| &set.id);
+        tcgdex
+            .set
+            .get_many(ids, crate::TCGdex::DEFAULT_CONCURRENCY)
+            .await
+    }
 }
 
 #[async_trait]
@@ -52,5 +77,18 @@ impl Fetchable for Serie {
 
 #[async_trait]
 impl Listable for Serie {
-    // Using the default implementation
+    fn merge_key(&self) -> Option<String> {
+        Some(self.id.clone())
+    }
+}
+
+impl Queryable for Serie {
+    fn field_values(&self, key: &str) -> Vec<FieldValue> {
+        match key.parse() {
+            Ok(SerieField::Id) => values([self.id.as_str()]),
+            Ok(SerieField::Name) => values([self.name.as_str()]),
+            Ok(SerieField::ReleaseDate) => values(self.release_date.map(|date| date.to_string())),
+            Err(_) => Vec::new(),
+        }
+    }
 }
@@ -5,7 +5,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::endpoints::{Fetchable, Listable};
 use crate::error::Result;
-use crate::models::Extension;
+use crate::models::{Date, Extension, SerieField};
+use crate::queryable::{values, FieldValue, Queryable};
 use crate::utils;
 
 /// Poku00e9mon TCG Serie Resume, contains basic information about a specific serie
@@ -17,6 +18,13 @@ pub struct SerieResume {
     pub name: String,
     /// The serie logo URL (without extension)
     pub logo: Option<String>,
+    /// The release date of the first set of the serie, `None` if the list does not send it
+    #[serde(
+        rename = "releaseDate",
+        default,
+        deserialize_with = "crate::utils::deserialize_lenient_date"
+    )]
+    pub release_date: Option<Date>,
 }
 
 impl SerieResume {
@@ -35,7 +43,7 @@ impl SerieResume {
     ) -> Result<Option<bytes::Bytes>> {
         match self.get_logo_url(extension) {
             Some(url) => {
-                let bytes = utils::download_image(tcgdex.client(), &url).await?;
+                let bytes = utils::download_image(tcgdex.http(), &url).await?;
                 Ok(Some(bytes))
             }
             None => Ok(None),
@@ -55,5 +63,18 @@ impl Fetchable for SerieResume {
 
 #[async_trait]
 impl Listable for SerieResume {
-    // Using the default implementation
+    fn merge_key(&self) -> Option<String> {
+        Some(self.id.clone())
+    }
+}
+
+impl Queryable for SerieResume {
+    fn field_values(&self, key: &str) -> Vec<FieldValue> {
+        match key.parse() {
+            Ok(SerieField::Id) => values([self.id.as_str()]),
+            Ok(SerieField::Name) => values([self.name.as_str()]),
+            Ok(SerieField::ReleaseDate) => values(self.release_date.map(|date| date.to_string())),
+            _ => Vec::new(),
+        }
+    }
 }
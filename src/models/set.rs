@@ -6,7 +6,8 @@ use serde::{Deserialize, Serialize};
 use crate::endpoints::{Fetchable, Listable};
 use crate::error::Result;
 use crate::models::common::*;
-use crate::models::{CardResume, Extension, SerieResume};
+use crate::models::{Card, CardResume, Date, Extension, SerieResume, SetField, Timestamp};
+use crate::queryable::{values, FieldValue, Queryable};
 use crate::utils;
 
 /// Poku00e9mon TCG Set, contains all information about a specific set
@@ -22,19 +23,31 @@ pub struct Set {
     pub symbol: Option<String>,
     /// The number of cards in the set
     #[serde(rename = "cardCount")]
-    pub card_count: SetCardCountResume,
+    pub card_count: SetCardCount,
     /// The serie this set is a part of
     pub serie: SerieResume,
     /// The Poku00e9mon TCG Online code
     #[serde(rename = "tcgOnline")]
     pub tcg_online: Option<String>,
-    /// The set release date as yyyy-mm-dd
-    #[serde(rename = "releaseDate")]
-    pub release_date: String,
+    /// The set release date, `None` if missing or malformed
+    #[serde(
+        rename = "releaseDate",
+        default,
+        deserialize_with = "crate::utils::deserialize_lenient_date"
+    )]
+    pub release_date: Option<Date>,
     /// The set legality information
     pub legal: Legal,
     /// The cards contained in this set
     pub cards: Vec<CardResume>,
+    /// The boosters of the set, `None` if the set has no distinct boosters
+    pub boosters: Option<Vec<Booster>>,
+    /// Date and time of the last update of the set data, `None` if missing or malformed
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_lenient_timestamp"
+    )]
+    pub updated: Option<Timestamp>,
 }
 
 impl Set {
@@ -53,7 +66,7 @@ impl Set {
     ) -> Result<Option<bytes::Bytes>> {
         match self.get_logo_url(extension) {
             Some(url) => {
-                let bytes = utils::download_image(tcgdex.client(), &url).await?;
+                let bytes = utils::download_image(tcgdex.http(), &url).await?;
                 Ok(Some(bytes))
             }
             None => Ok(None),
@@ -75,12 +88,52 @@ impl Set {
     ) -> Result<Option<bytes::Bytes>> {
         match self.get_symbol_url(extension) {
             Some(url) => {
-                let bytes = utils::download_image(tcgdex.client(), &url).await?;
+                let bytes = utils::download_image(tcgdex.http(), &url).await?;
                 Ok(Some(bytes))
             }
             None => Ok(None),
         }
     }
+
+    /// Get the booster of the set with this ID
+    pub fn booster(&self, booster_id: &str) -> Option<&Booster> {
+        self.boosters
+            .iter()
+            .flatten()
+            .find(|booster| booster.id == booster_id)
+    }
+
+    /// Fetch the full information of the cards found in the booster with this ID, in the set order
+    ///
+    /// This sends one request per card of the set, see [`fetch_all_cards`](Self::fetch_all_cards),
+    /// since cards without booster list are in every booster and a `boosters.id` query
+    /// would miss them. Cards that could not be fetched are kept as errors, since
+    /// they may be in the booster.
+    pub async fn cards_in_booster(
+        &self,
+        tcgdex: &crate::TCGdex,
+        booster_id: &str,
+    ) -> Vec<Result<Card>> {
+        self.fetch_all_cards(tcgdex)
+            .await
+            .into_iter()
+            .filter(|card| {
+                card.as_ref()
+                    .map_or(true, |card| card.is_in_booster(booster_id))
+            })
+            .collect()
+    }
+
+    /// Fetch the full information of every card of the set, in the set order
+    ///
+    /// Each card has its own result, so a missing card does not prevent getting the others.
+    pub async fn fetch_all_cards(&self, tcgdex: &crate::TCGdex) -> Vec<Result<Card>> {
+        let ids = self.cards.iter().map(|card| &card.id);
+        tcgdex
+            .card
+            .get_many(ids, crate::TCGdex::DEFAULT_CONCURRENCY)
+            .await
+    }
 }
 
 #[async_trait]
@@ -90,5 +143,27 @@ impl Fetchable for Set {
 
 #[async_trait]
 impl Listable for Set {
-    // Using the default implementation
+    fn merge_key(&self) -> Option<String> {
+        Some(self.id.clone())
+    }
+}
+
+impl Queryable for Set {
+    fn field_values(&self, key: &str) -> Vec<FieldValue> {
+        let Ok(field) = key.parse() else {
+            return Vec::new();
+        };
+        match field {
+            SetField::Id => values([self.id.as_str()]),
+            SetField::Name => values([self.name.as_str()]),
+            SetField::SerieId => values([self.serie.id.as_str()]),
+            SetField::SerieName => values([self.serie.name.as_str()]),
+            SetField::TcgOnline => values(self.tcg_online.as_deref()),
+            SetField::ReleaseDate => values(self.release_date.map(|date| date.to_string())),
+            SetField::CardCountTotal => values([self.card_count.total]),
+            SetField::CardCountOfficial => values([self.card_count.official]),
+            SetField::LegalStandard => values([self.legal.standard]),
+            SetField::LegalExpanded => values([self.legal.expanded]),
+        }
+    }
 }
@@ -6,7 +6,8 @@ use serde::{Deserialize, Serialize};
 use crate::endpoints::{Fetchable, Listable};
 use crate::error::Result;
 use crate::models::common::SetCardCountResume;
-use crate::models::Extension;
+use crate::models::{Date, Extension, SetField};
+use crate::queryable::{values, FieldValue, Queryable};
 use crate::utils;
 
 /// Set resume, contains basic information about a specific set
@@ -23,6 +24,13 @@ pub struct SetResume {
     /// The number of cards in the set
     #[serde(rename = "cardCount")]
     pub card_count: SetCardCountResume,
+    /// The set release date, `None` if the list does not send it
+    #[serde(
+        rename = "releaseDate",
+        default,
+        deserialize_with = "crate::utils::deserialize_lenient_date"
+    )]
+    pub release_date: Option<Date>,
 }
 
 impl SetResume {
@@ -41,7 +49,7 @@ impl SetResume {
     ) -> Result<Option<bytes::Bytes>> {
         match self.get_logo_url(extension) {
             Some(url) => {
-                let bytes = utils::download_image(tcgdex.client(), &url).await?;
+                let bytes = utils::download_image(tcgdex.http(), &url).await?;
                 Ok(Some(bytes))
             }
             None => Ok(None),
@@ -63,7 +71,7 @@ impl SetResume {
     ) -> Result<Option<bytes::Bytes>> {
         match self.get_symbol_url(extension) {
             Some(url) => {
-                let bytes = utils::download_image(tcgdex.client(), &url).await?;
+                let bytes = utils::download_image(tcgdex.http(), &url).await?;
                 Ok(Some(bytes))
             }
             None => Ok(None),
@@ -83,5 +91,20 @@ impl Fetchable for SetResume {
 
 #[async_trait]
 impl Listable for SetResume {
-    // Using the default implementation
+    fn merge_key(&self) -> Option<String> {
+        Some(self.id.clone())
+    }
+}
+
+impl Queryable for SetResume {
+    fn field_values(&self, key: &str) -> Vec<FieldValue> {
+        match key.parse() {
+            Ok(SetField::Id) => values([self.id.as_str()]),
+            Ok(SetField::Name) => values([self.name.as_str()]),
+            Ok(SetField::CardCountTotal) => values([self.card_count.total]),
+            Ok(SetField::CardCountOfficial) => values([self.card_count.official]),
+            Ok(SetField::ReleaseDate) => values(self.release_date.map(|date| date.to_string())),
+            _ => Vec::new(),
+        }
+    }
 }
@@ -1,22 +1,219 @@
 //! Query builder for filtering API results
 
+use std::cmp::Ordering;
 use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, Display, EnumString};
+
+use crate::error::{Error, Result};
+use crate::queryable::{self, Queryable};
 
 /// A query builder for filtering API results
+///
+/// Keys can be strings or, to avoid typos, the fields of the queried resource:
+/// [`CardField`](crate::CardField), [`SetField`](crate::SetField) or
+/// [`SerieField`](crate::SerieField).
+///
+/// Conditions are combined with AND. [`equal_any`](Self::equal_any) and
+/// [`contains_any`](Self::contains_any) match one of several values of a
+/// field, and [`any_of`](Self::any_of) matches one of several sub-queries.
+///
+/// A query can be parsed back from its query string with [`parse`](Self::parse),
+/// or stored with serde.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Query {
     params: Vec<QueryParam>,
+    /// Groups of alternatives the API cannot combine, a record must match one
+    /// alternative of every group
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    any_of: Vec<Vec<Vec<QueryParam>>>,
 }
 
 /// A query parameter for filtering API results
-struct QueryParam {
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueryParam {
     key: String,
     value: String,
 }
 
+/// Filter operator of a query parameter, used as value prefix, e.g. `eq:Fire`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumString, AsRefStr)]
+pub enum Operator {
+    /// The field contains the value, without prefix
+    #[strum(serialize = "like")]
+    Contains,
+    /// The field equals the value
+    #[strum(serialize = "eq")]
+    Equal,
+    /// The field does not equal the value
+    #[strum(serialize = "neq")]
+    NotEqual,
+    /// The field does not contain the value
+    #[strum(serialize = "not")]
+    NotContains,
+    /// The field is greater than the value
+    #[strum(serialize = "gt")]
+    GreaterThan,
+    /// The field is greater than or equal to the value
+    #[strum(serialize = "gte")]
+    GreaterOrEqual,
+    /// The field is less than the value
+    #[strum(serialize = "lt")]
+    LessThan,
+    /// The field is less than or equal to the value
+    #[strum(serialize = "lte")]
+    LessOrEqual,
+    /// The field is not set
+    #[strum(serialize = "null")]
+    Null,
+    /// The field is set
+    #[strum(serialize = "notnull")]
+    NotNull,
+}
+
+impl Operator {
+    /// Check if values of this operator can be combined with `|` in a single parameter
+    fn is_mergeable(self) -> bool {
+        matches!(self, Self::Contains | Self::Equal)
+    }
+}
+
+impl QueryParam {
+    /// Get the key of the parameter, a field name or a sorting or pagination key
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Get the filter operator, `None` for sorting and pagination parameters
+    pub fn operator(&self) -> Option<Operator> {
+        self.is_filter().then(|| split_operator(&self.value).0)
+    }
+
+    /// Get the value compared by the operator, or the whole value of sorting
+    /// and pagination parameters
+    pub fn value(&self) -> &str {
+        match self.is_filter() {
+            true => split_operator(&self.value).1,
+            false => &self.value,
+        }
+    }
+
+    // Check if the parameter filters records, rather than sorting or paginating them
+    fn is_filter(&self) -> bool {
+        !self.key.starts_with("sort:") && !self.key.starts_with("pagination:")
+    }
+}
+
 impl Query {
+    /// Maximum number of requests a query with alternatives can be sent as
+    pub const MAX_REQUESTS: usize = 32;
+
     /// Create a new empty query
     pub fn new() -> Self {
-        Self { params: Vec::new() }
+        Self {
+            params: Vec::new(),
+            any_of: Vec::new(),
+        }
+    }
+
+    /// Parse a query string, as built by [`build`](Self::build)
+    ///
+    /// The leading `?` is optional. Fails on parameters without key and on
+    /// pagination parameters that are not positive numbers.
+    ///
+    /// A query string holds no alternatives needing separate requests, so
+    /// queries with such alternatives do not round-trip through [`build`](Self::build):
+    /// save them with serde instead.
+    pub fn parse(query: &str) -> Result<Self> {
+        let query = query.strip_prefix('?').unwrap_or(query);
+        let mut params = Vec::new();
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+            let param = QueryParam {
+                key: key.into_owned(),
+                value: value.into_owned(),
+            };
+            if param.key.is_empty() {
+                return Err(Error::InvalidQuery(format!(
+                    "parameter without key: {}",
+                    param.value
+                )));
+            }
+            if param.key.starts_with("pagination:")
+                && !param.value.parse::<u32>().is_ok_and(|number| number > 0)
+            {
+                return Err(Error::InvalidQuery(format!(
+                    "{} must be a positive number, got {:?}",
+                    param.key, param.value
+                )));
+            }
+            params.push(param);
+        }
+
+        Ok(Self {
+            params,
+            any_of: Vec::new(),
+        })
+    }
+
+    /// Get the parameters sent with every request, in order
+    ///
+    /// Alternatives of [`any_of`](Self::any_of) needing separate requests are
+    /// not included.
+    pub fn params(&self) -> &[QueryParam] {
+        &self.params
+    }
+
+    /// Add a query parameter for filtering records where the field equals one of the values
+    pub fn equal_any<K, V, I>(&mut self, key: K, values: I) -> &mut Self
+    where
+        K: fmt::Display,
+        V: fmt::Display,
+        I: IntoIterator<Item = V>,
+    {
+        self.params.push(QueryParam {
+            key: key.to_string(),
+            value: format!("eq:{}", join_values(values)),
+        });
+        self
+    }
+
+    /// Add a query parameter for filtering records where the field contains one of the values
+    pub fn contains_any<K, V, I>(&mut self, key: K, values: I) -> &mut Self
+    where
+        K: fmt::Display,
+        V: fmt::Display,
+        I: IntoIterator<Item = V>,
+    {
+        self.params.push(QueryParam {
+            key: key.to_string(),
+            value: join_values(values),
+        });
+        self
+    }
+
+    /// Add a condition matching records that match at least one of the alternatives
+    ///
+    /// Alternatives filtering the same field with `eq` or `contains` are sent
+    /// as a single `|`-separated parameter. Other alternatives are sent as
+    /// separate requests whose results are merged, see [`build_all`](Self::build_all).
+    pub fn any_of<I>(&mut self, alternatives: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Query>,
+    {
+        let alternatives: Vec<Query> = alternatives.into_iter().collect();
+        if alternatives.is_empty() {
+            return self;
+        }
+
+        match merge_alternatives(&alternatives) {
+            Some(param) => self.params.push(param),
+            None => self
+                .any_of
+                .push(alternatives.iter().flat_map(Query::branches).collect()),
+        }
+        self
     }
 
     /// Add a query parameter for filtering records that contain the given value
@@ -187,18 +384,48 @@ impl Query {
     }
 
     /// Build the query string
+    ///
+    /// This is lossy when [`needs_several_requests`](Self::needs_several_requests):
+    /// alternatives of [`any_of`](Self::any_of) needing separate requests are
+    /// not included, so the query string matches more records than the query.
+    /// Use [`build_all`](Self::build_all) to send them, and serde to save them.
     pub fn build(&self) -> String {
-        if self.params.is_empty() {
-            return String::new();
+        build_params(&self.params)
+    }
+
+    /// Check if the query needs several requests to cover all its alternatives
+    pub fn needs_several_requests(&self) -> bool {
+        !self.any_of.is_empty()
+    }
+
+    /// Build one query string per request needed to cover all the alternatives
+    ///
+    /// Records matching the query are the union of the results of these requests.
+    /// Fails with [`Error::InvalidQuery`] when more than [`MAX_REQUESTS`](Self::MAX_REQUESTS)
+    /// requests are needed, or when several requests are needed with sorting or
+    /// pagination, which the API would apply to each request instead of the union.
+    pub fn build_all(&self) -> Result<Vec<String>> {
+        let requests = self.any_of.iter().fold(1_usize, |requests, group| {
+            requests.saturating_mul(group.len())
+        });
+        if requests > Self::MAX_REQUESTS {
+            return Err(Error::InvalidQuery(format!(
+                "{} requests needed for the alternatives, at most {} are allowed",
+                requests,
+                Self::MAX_REQUESTS
+            )));
         }
 
-        let params: Vec<String> = self
-            .params
-            .iter()
-            .map(|param| format!("{0}={1}", encode(&param.key), encode(&param.value)))
-            .collect();
+        let branches = self.branches();
+        let sorted_or_paginated = || branches.iter().flatten().any(|param| !param.is_filter());
+        if branches.len() > 1 && sorted_or_paginated() {
+            return Err(Error::InvalidQuery(
+                "sorting and pagination cannot be combined with alternatives needing several requests"
+                    .to_string(),
+            ));
+        }
 
-        format!("?{}", params.join("&"))
+        Ok(branches.iter().map(|params| build_params(params)).collect())
     }
 
     // Convenience aliases
@@ -276,18 +503,215 @@ impl Query {
     }
 }
 
+impl Query {
+    /// Check if a record matches the conditions of the query, as the API would
+    ///
+    /// Sorting and pagination are ignored, see [`apply`](Self::apply).
+    pub fn matches(&self, item: &impl Queryable) -> bool {
+        let matches_all = |params: &[QueryParam]| params.iter().all(|param| param.matches(item));
+        matches_all(&self.params)
+            && self
+                .any_of
+                .iter()
+                .all(|group| group.iter().any(|alternative| matches_all(alternative)))
+    }
+
+    /// Filter, sort and paginate records in place, as the API would
+    pub fn apply<T: Queryable>(&self, items: &mut Vec<T>) {
+        items.retain(|item| self.matches(item));
+
+        if let Some(field) = self.last_value("sort:field") {
+            let descending = self
+                .last_value("sort:order")
+                .is_some_and(|order| order.eq_ignore_ascii_case("desc"));
+            // Records without the field always come last
+            items.sort_by_cached_key(|item| SortKey {
+                value: item.field_values(field).into_iter().next(),
+                descending,
+            });
+        }
+
+        if let Some(per_page) = self.last_number("pagination:itemsPerPage") {
+            let page = self.last_number("pagination:page").unwrap_or(1).max(1);
+            let start = (page - 1).saturating_mul(per_page).min(items.len());
+            items.drain(..start);
+            items.truncate(per_page);
+        }
+    }
+
+    // Get the value of the last parameter with the key
+    fn last_value(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .rev()
+            .find(|param| param.key == key)
+            .map(|param| param.value.as_str())
+    }
+
+    // Get the value of the last parameter with the key, as a number
+    fn last_number(&self, key: &str) -> Option<usize> {
+        self.last_value(key)?.parse().ok()
+    }
+
+    // Copy the query without its pagination parameters, in the alternatives too
+    pub(crate) fn without_pagination(&self) -> Self {
+        let keep = |params: &Vec<QueryParam>| -> Vec<QueryParam> {
+            params
+                .iter()
+                .filter(|param| !param.key.starts_with("pagination:"))
+                .cloned()
+                .collect()
+        };
+        Self {
+            params: keep(&self.params),
+            any_of: self
+                .any_of
+                .iter()
+                .map(|group| group.iter().map(keep).collect())
+                .collect(),
+        }
+    }
+
+    // Expand the alternatives into the parameters of each request
+    fn branches(&self) -> Vec<Vec<QueryParam>> {
+        let mut branches = vec![self.params.clone()];
+        for group in &self.any_of {
+            branches = branches
+                .iter()
+                .flat_map(|branch| {
+                    group
+                        .iter()
+                        .map(move |alternative| branch.iter().chain(alternative).cloned().collect())
+                })
+                .collect();
+        }
+        branches
+    }
+}
+
+impl QueryParam {
+    // Check if a record matches the parameter, sorting and pagination always match
+    fn matches(&self, item: &impl Queryable) -> bool {
+        if !self.is_filter() {
+            return true;
+        }
+        let (operator, operand) = split_operator(&self.value);
+        queryable::matches(&item.field_values(&self.key), operator, operand)
+    }
+}
+
+/// Sort key of a record, ordering records without a value last
+struct SortKey {
+    value: Option<queryable::FieldValue>,
+    descending: bool,
+}
+
+impl Ord for SortKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.value, &other.value) {
+            (Some(a), Some(b)) if self.descending => b.sort_cmp(a),
+            (Some(a), Some(b)) => a.sort_cmp(b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+impl PartialOrd for SortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for SortKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SortKey {}
+
 impl Default for Query {
     fn default() -> Self {
         Self::new()
     }
 }
 
-/// URL encode a string, replacing spaces with %20 and removing certain characters
+impl FromStr for Query {
+    type Err = Error;
+
+    fn from_str(query: &str) -> Result<Self> {
+        Self::parse(query)
+    }
+}
+
+/// Build a query string from parameters
+fn build_params(params: &[QueryParam]) -> String {
+    if params.is_empty() {
+        return String::new();
+    }
+
+    let params: Vec<String> = params
+        .iter()
+        .map(|param| format!("{0}={1}", encode(&param.key), encode(&param.value)))
+        .collect();
+
+    format!("?{}", params.join("&"))
+}
+
+/// Join values with `|`, the API syntax for matching one of several values
+fn join_values<V: fmt::Display>(values: impl IntoIterator<Item = V>) -> String {
+    values
+        .into_iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join("|")
+}
+
+/// Split a parameter value into its operator and its operand
+fn split_operator(value: &str) -> (Operator, &str) {
+    value
+        .split_once(':')
+        .and_then(|(operator, operand)| Some((operator.parse().ok()?, operand)))
+        .unwrap_or((Operator::Contains, value))
+}
+
+/// Merge alternatives into a single `|`-separated parameter, if the API supports it
+fn merge_alternatives(alternatives: &[Query]) -> Option<QueryParam> {
+    let mut params = Vec::new();
+    for alternative in alternatives {
+        match alternative.params.as_slice() {
+            [param] if alternative.any_of.is_empty() => params.push(param),
+            _ => return None,
+        }
+    }
+
+    let first = params.first()?;
+    let (operator, _) = split_operator(&first.value);
+    let mergeable = operator.is_mergeable()
+        && params.iter().all(|param| {
+            let (param_operator, operand) = split_operator(&param.value);
+            param.key == first.key && param_operator == operator && !operand.contains('|')
+        });
+    if !mergeable {
+        return None;
+    }
+
+    let value = join_values(params.iter().map(|param| split_operator(&param.value).1));
+    Some(QueryParam {
+        key: first.key.clone(),
+        value: match operator {
+            Operator::Contains => value,
+            _ => format!("{}:{}", operator, value),
+        },
+    })
+}
+
+/// URL encode a string, encoding spaces as %20
 fn encode(s: &str) -> String {
-    // Replace spaces with %20 and encode other characters
-    let s = s.replace(' ', "%20");
-    // Remove quotes, backslashes, and certain Unicode characters (simplified for now)
-    let s = s.replace(['"', '\'', '\\'], "");
-    // Encode the string
-    url::form_urlencoded::byte_serialize(s.as_bytes()).collect()
+    // A literal `+` is encoded as %2B, so any `+` left is a space
+    url::form_urlencoded::byte_serialize(s.as_bytes())
+        .collect::<String>()
+        .replace('+', "%20")
 }
@@ -0,0 +1,148 @@
+//! Local evaluation of query conditions against in-memory records
+
+use std::cmp::Ordering;
+
+use crate::models::Localized;
+use crate::query::Operator;
+
+/// Value of a record field, as compared by query conditions
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldValue {
+    /// Text, compared without case
+    Text(String),
+    /// Number, compared numerically
+    Number(f64),
+    /// Boolean, equal to `true` or `false`
+    Bool(bool),
+}
+
+/// A record that a [`Query`](crate::Query) can filter and sort locally
+///
+/// Keys are the field names used by the API, e.g. `hp`, `set.id` or `legal.standard`.
+pub trait Queryable {
+    /// Get the values of a field, empty when the field is not set or unknown
+    ///
+    /// Fields holding a list, like `types`, have one value per element.
+    fn field_values(&self, key: &str) -> Vec<FieldValue>;
+}
+
+impl From<&str> for FieldValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<String> for FieldValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<i32> for FieldValue {
+    fn from(value: i32) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<u16> for FieldValue {
+    fn from(value: u16) -> Self {
+        Self::Number(value.into())
+    }
+}
+
+impl From<f64> for FieldValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<bool> for FieldValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl FieldValue {
+    /// Text representation of the value, lowercased
+    fn text(&self) -> String {
+        match self {
+            Self::Text(text) => text.to_lowercase(),
+            Self::Number(number) => number.to_string(),
+            Self::Bool(value) => value.to_string(),
+        }
+    }
+
+    /// Check if the value equals the operand, without case
+    fn equals(&self, operand: &str) -> bool {
+        match self {
+            Self::Number(number) => operand.trim().parse() == Ok(*number),
+            _ => self.text() == operand.to_lowercase(),
+        }
+    }
+
+    /// Check if the value contains the operand, without case
+    fn contains(&self, operand: &str) -> bool {
+        self.text().contains(&operand.to_lowercase())
+    }
+
+    /// Compare the value to the operand, numerically for numbers
+    fn compare(&self, operand: &str) -> Option<Ordering> {
+        match self {
+            Self::Number(number) => number.partial_cmp(&operand.trim().parse().ok()?),
+            Self::Text(text) => Some(text.to_lowercase().cmp(&operand.to_lowercase())),
+            Self::Bool(_) => None,
+        }
+    }
+
+    /// Order two values for sorting
+    pub(crate) fn sort_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a.total_cmp(b),
+            _ => self.text().cmp(&other.text()),
+        }
+    }
+}
+
+/// Build the values of a field from anything iterable, including an `Option`
+pub(crate) fn values<V: Into<FieldValue>>(values: impl IntoIterator<Item = V>) -> Vec<FieldValue> {
+    values.into_iter().map(Into::into).collect()
+}
+
+/// Build the values of a localized field, with the names of each value in every language
+///
+/// Records do not know the language they were fetched in, so a condition on a
+/// localized name, e.g. `types=eq:Feu`, matches whatever the query language.
+pub(crate) fn localized_values<'a, L: Localized>(
+    values: impl IntoIterator<Item = &'a L>,
+) -> Vec<FieldValue> {
+    values
+        .into_iter()
+        .flat_map(Localized::all_names)
+        .map(FieldValue::from)
+        .collect()
+}
+
+/// Check if field values match a condition, with the same rules as the API
+///
+/// For `contains` and `eq`, the operand can hold several alternatives separated by `|`.
+pub(crate) fn matches(values: &[FieldValue], operator: Operator, operand: &str) -> bool {
+    let any = |check: &dyn Fn(&FieldValue) -> bool| values.iter().any(check);
+    match operator {
+        Operator::Contains => operand
+            .split('|')
+            .any(|alternative| any(&|value| value.contains(alternative))),
+        Operator::Equal => operand
+            .split('|')
+            .any(|alternative| any(&|value| value.equals(alternative))),
+        Operator::NotEqual => !any(&|value| value.equals(operand)),
+        Operator::NotContains => !any(&|value| value.contains(operand)),
+        Operator::GreaterThan => any(&|value| value.compare(operand) == Some(Ordering::Greater)),
+        Operator::GreaterOrEqual => {
+            any(&|value| value.compare(operand).is_some_and(Ordering::is_ge))
+        }
+        Operator::LessThan => any(&|value| value.compare(operand) == Some(Ordering::Less)),
+        Operator::LessOrEqual => any(&|value| value.compare(operand).is_some_and(Ordering::is_le)),
+        Operator::Null => values.is_empty(),
+        Operator::NotNull => !values.is_empty(),
+    }
+}
@@ -0,0 +1,142 @@
+//! Client-side rate limiting of API requests
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Request budget shared by every endpoint of a TCGdex client
+///
+/// Requests are throttled with a token bucket refilled at `requests_per_second`
+/// and holding up to `burst` tokens, while `max_in_flight` bounds the number of
+/// requests running at the same time. `None` disables the corresponding limit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimit {
+    /// Maximum sustained number of requests started per second
+    pub requests_per_second: Option<f64>,
+    /// Number of requests that can be started at once before throttling kicks in
+    pub burst: u32,
+    /// Maximum number of requests running concurrently
+    pub max_in_flight: Option<usize>,
+}
+
+impl RateLimit {
+    /// Create a rate limit that never throttles requests
+    pub fn unlimited() -> Self {
+        Self {
+            requests_per_second: None,
+            burst: 1,
+            max_in_flight: None,
+        }
+    }
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self {
+            requests_per_second: Some(20.0),
+            burst: 20,
+            max_in_flight: Some(8),
+        }
+    }
+}
+
+/// Longest single wait for a token, the bucket is checked again afterwards
+const MAX_WAIT: Duration = Duration::from_secs(60);
+
+/// Token bucket state
+#[derive(Debug)]
+struct Bucket {
+    /// Tokens currently available
+    tokens: f64,
+    /// Last time tokens were added to the bucket
+    refilled_at: Instant,
+}
+
+/// Rate limiter enforcing a `RateLimit`, shared between clones of the HTTP layer
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    /// The enforced limits
+    limit: RateLimit,
+    /// Token bucket, present when the request rate is limited
+    bucket: Option<Mutex<Bucket>>,
+    /// Permits for in-flight requests, present when concurrency is limited
+    in_flight: Option<Arc<Semaphore>>,
+}
+
+impl RateLimiter {
+    /// Create a new rate limiter
+    pub(crate) fn new(limit: RateLimit) -> Self {
+        let bucket = limit.requests_per_second.map(|_| {
+            Mutex::new(Bucket {
+                tokens: limit.burst.max(1) as f64,
+                refilled_at: Instant::now(),
+            })
+        });
+        let in_flight = limit
+            .max_in_flight
+            .map(|max| Arc::new(Semaphore::new(max.clamp(1, Semaphore::MAX_PERMITS))));
+
+        Self {
+            limit,
+            bucket,
+            in_flight,
+        }
+    }
+
+    /// Get the enforced limits
+    pub(crate) fn limit(&self) -> &RateLimit {
+        &self.limit
+    }
+
+    /// Wait until a request can be started
+    ///
+    /// The returned permit must be held until the request completes.
+    pub(crate) async fn acquire(&self) -> Option<OwnedSemaphorePermit> {
+        let permit = match &self.in_flight {
+            Some(semaphore) => Some(
+                semaphore
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .expect("rate limiter semaphore is never closed"),
+            ),
+            None => None,
+        };
+
+        while let Some(wait) = self.take_token() {
+            tokio::time::sleep(wait).await;
+        }
+
+        permit
+    }
+
+    /// Take a token from the bucket, returning how long to wait when it is empty
+    fn take_token(&self) -> Option<Duration> {
+        let (bucket, rate) = match (&self.bucket, self.limit.requests_per_second) {
+            (Some(bucket), Some(rate)) if rate > 0.0 => (bucket, rate),
+            _ => return None,
+        };
+
+        let mut bucket = bucket.lock().unwrap_or_else(|err| err.into_inner());
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * rate).min(self.limit.burst.max(1) as f64);
+        bucket.refilled_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            None
+        } else {
+            // Tiny rates give waits too long for a `Duration`
+            let wait = Duration::try_from_secs_f64((1.0 - bucket.tokens) / rate);
+            Some(wait.map_or(MAX_WAIT, |wait| wait.min(MAX_WAIT)))
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(RateLimit::default())
+    }
+}
@@ -0,0 +1,435 @@
+//! Typed query builders, one per resource
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use futures::stream::Stream;
+use strum_macros::{Display, EnumString};
+
+use crate::endpoints::{Endpoint, Fetchable, Listable};
+use crate::error::Result;
+use crate::models::{
+    Card, CardField, CardResume, Category, Date, Localized, PokemonType, PriceField, Rarity, Serie,
+    SerieField, SerieResume, Set, SetField, SetResume, Stage,
+};
+use crate::query::Query;
+
+/// Order of sorted results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Display, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum SortOrder {
+    /// Smallest values first
+    #[default]
+    Asc,
+    /// Largest values first
+    Desc,
+}
+
+/// A query on a single endpoint, only accepting the fields of its resource
+///
+/// Created with `query()` on the cards, sets and series endpoints, e.g.
+/// [`CardQuery`] with `tcgdex.card.query()`:
+///
+/// ```
+/// use tcgdex_sdk::{CardField, SortOrder, TCGdex};
+///
+/// let tcgdex = TCGdex::default();
+/// let query = tcgdex
+///     .card
+///     .query()
+///     .hp_gte(100)
+///     .types_include("Fire")
+///     .sort_by(CardField::Name, SortOrder::Asc)
+///     .page(2, 50)
+///     .build();
+/// ```
+///
+/// Fields of another resource are rejected at compile time:
+///
+/// ```compile_fail
+/// use tcgdex_sdk::{SetField, TCGdex};
+///
+/// let tcgdex = TCGdex::default();
+/// let query = tcgdex.card.query().equal(SetField::SerieId, "base");
+/// ```
+pub struct ResourceQuery<'a, Item, List, Field> {
+    /// Endpoint the query is sent to
+    endpoint: &'a Endpoint<Item, List>,
+    /// Query being built
+    query: Query,
+    _field: PhantomData<Field>,
+}
+
+/// A query on the cards endpoint
+pub type CardQuery<'a> = ResourceQuery<'a, Card, CardResume, CardField>;
+/// A query on the sets endpoint
+pub type SetQuery<'a> = ResourceQuery<'a, Set, SetResume, SetField>;
+/// A query on the series endpoint
+pub type SerieQuery<'a> = ResourceQuery<'a, Serie, SerieResume, SerieField>;
+
+impl<'a, Item, List, Field> ResourceQuery<'a, Item, List, Field>
+where
+    Field: fmt::Display,
+{
+    /// Create an empty query on the given endpoint
+    fn new(endpoint: &'a Endpoint<Item, List>) -> Self {
+        Self {
+            endpoint,
+            query: Query::new(),
+            _field: PhantomData,
+        }
+    }
+
+    /// Keep items where the field contains the value
+    pub fn contains(mut self, field: Field, value: impl fmt::Display) -> Self {
+        self.query.contains(field, value);
+        self
+    }
+
+    /// Keep items where the field does not contain the value
+    pub fn not_contains(mut self, field: Field, value: impl fmt::Display) -> Self {
+        self.query.not_contains(field, value);
+        self
+    }
+
+    /// Keep items where the field equals the value
+    pub fn equal(mut self, field: Field, value: impl fmt::Display) -> Self {
+        self.query.equal(field, value);
+        self
+    }
+
+    /// Keep items where the field equals one of the values
+    pub fn equal_any<V: fmt::Display>(
+        mut self,
+        field: Field,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        self.query.equal_any(field, values);
+        self
+    }
+
+    /// Keep items where the field contains one of the values
+    pub fn contains_any<V: fmt::Display>(
+        mut self,
+        field: Field,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        self.query.contains_any(field, values);
+        self
+    }
+
+    /// Keep items matching at least one of the alternatives, see [`Query::any_of`]
+    ///
+    /// Alternatives are usually started from the same endpoint, e.g.
+    /// `tcgdex.card.query().hp_gte(200)`.
+    pub fn any_of(mut self, alternatives: impl IntoIterator<Item = Self>) -> Self {
+        self.query.any_of(
+            alternatives
+                .into_iter()
+                .map(|alternative| alternative.query),
+        );
+        self
+    }
+
+    /// Keep items where the field does not equal the value
+    pub fn not_equal(mut self, field: Field, value: impl fmt::Display) -> Self {
+        self.query.not_equal(field, value);
+        self
+    }
+
+    /// Keep items where the field is greater than the value
+    pub fn greater_than(mut self, field: Field, value: impl fmt::Display) -> Self {
+        self.query.greater_than(field, value);
+        self
+    }
+
+    /// Keep items where the field is greater than or equal to the value
+    pub fn greater_or_equal_than(mut self, field: Field, value: impl fmt::Display) -> Self {
+        self.query.greater_or_equal_than(field, value);
+        self
+    }
+
+    /// Keep items where the field is less than the value
+    pub fn less_than(mut self, field: Field, value: impl fmt::Display) -> Self {
+        self.query.less_than(field, value);
+        self
+    }
+
+    /// Keep items where the field is less than or equal to the value
+    pub fn less_or_equal_than(mut self, field: Field, value: impl fmt::Display) -> Self {
+        self.query.less_or_equal_than(field, value);
+        self
+    }
+
+    /// Keep items where the field is not set
+    pub fn is_null(mut self, field: Field) -> Self {
+        self.query.is_null(field);
+        self
+    }
+
+    /// Keep items where the field is set
+    pub fn not_null(mut self, field: Field) -> Self {
+        self.query.not_null(field);
+        self
+    }
+
+    /// Sort the items by the field
+    pub fn sort_by(mut self, field: Field, order: SortOrder) -> Self {
+        self.query.sort(field, order);
+        self
+    }
+
+    /// Only get the given page, starting at 1
+    pub fn page(mut self, page: u32, items_per_page: u32) -> Self {
+        self.query.paginate(page, items_per_page);
+        self
+    }
+
+    /// Only get the first items
+    pub fn limit(mut self, count: u32) -> Self {
+        self.query.limit(count);
+        self
+    }
+
+    /// Get the query built so far, to use it with [`Endpoint::list`]
+    pub fn build(self) -> Query {
+        self.query
+    }
+}
+
+impl<Item, List, Field> ResourceQuery<'_, Item, List, Field>
+where
+    Item: Fetchable + Clone + Send + Sync + 'static,
+    List: Listable + Clone + Send + Sync + 'static,
+{
+    /// Send the query and get the matching items
+    pub async fn list(&self) -> Result<Vec<List>> {
+        self.endpoint.list(Some(&self.query)).await
+    }
+
+    /// Stream every matching item, see [`Endpoint::stream`]
+    ///
+    /// The query must not be paginated itself.
+    pub fn stream(&self) -> impl Stream<Item = Result<List>> + Send {
+        self.endpoint.stream(Some(&self.query))
+    }
+}
+
+impl CardQuery<'_> {
+    /// Keep cards with exactly this name
+    pub fn name(self, name: impl fmt::Display) -> Self {
+        self.equal(CardField::Name, name)
+    }
+
+    /// Keep cards whose name contains the text
+    pub fn name_contains(self, text: impl fmt::Display) -> Self {
+        self.contains(CardField::Name, text)
+    }
+
+    /// Keep cards with exactly this HP
+    pub fn hp(self, hp: u32) -> Self {
+        self.equal(CardField::Hp, hp)
+    }
+
+    /// Keep cards with at least this HP
+    pub fn hp_gte(self, hp: u32) -> Self {
+        self.greater_or_equal_than(CardField::Hp, hp)
+    }
+
+    /// Keep cards with at most this HP
+    pub fn hp_lte(self, hp: u32) -> Self {
+        self.less_or_equal_than(CardField::Hp, hp)
+    }
+
+    /// Keep Pokémon having this type
+    ///
+    /// Takes a [`PokemonType`] or its name, a name being parsed in any
+    /// language. The type is then sent in the language of the endpoint, e.g.
+    /// `Feu` for `PokemonType::Fire` or `"Fire"` in French, and names matching
+    /// no type are sent as given.
+    pub fn types_include(self, type_: impl Into<PokemonType>) -> Self {
+        let name = self.localized(type_.into());
+        self.equal(CardField::Types, name)
+    }
+
+    /// Keep Pokémon having one of these types, see [`Self::types_include`]
+    pub fn types_include_any<T: Into<PokemonType>>(
+        self,
+        types: impl IntoIterator<Item = T>,
+    ) -> Self {
+        let names: Vec<String> = types
+            .into_iter()
+            .map(|type_| self.localized(type_.into()))
+            .collect();
+        self.equal_any(CardField::Types, names)
+    }
+
+    /// Keep cards of this rarity, named in any language
+    pub fn rarity(self, rarity: impl Into<Rarity>) -> Self {
+        let name = self.localized(rarity.into());
+        self.equal(CardField::Rarity, name)
+    }
+
+    /// Keep cards of one of these rarities, named in any language
+    pub fn rarity_in<R: Into<Rarity>>(self, rarities: impl IntoIterator<Item = R>) -> Self {
+        let names: Vec<String> = rarities
+            .into_iter()
+            .map(|rarity| self.localized(rarity.into()))
+            .collect();
+        self.equal_any(CardField::Rarity, names)
+    }
+
+    /// Keep cards of this category (Pokemon, Trainer or Energy), named in any language
+    pub fn category(self, category: impl Into<Category>) -> Self {
+        let name = self.localized(category.into());
+        self.equal(CardField::Category, name)
+    }
+
+    /// Keep cards drawn by this illustrator
+    pub fn illustrator(self, illustrator: impl fmt::Display) -> Self {
+        self.equal(CardField::Illustrator, illustrator)
+    }
+
+    /// Keep Pokémon at this evolution stage, named in any language
+    pub fn stage(self, stage: impl Into<Stage>) -> Self {
+        let name = self.localized(stage.into());
+        self.equal(CardField::Stage, name)
+    }
+
+    /// Keep cards of the set with this ID
+    pub fn set(self, set_id: impl fmt::Display) -> Self {
+        self.equal(CardField::SetId, set_id)
+    }
+
+    /// Keep cards of this Pokédex ID
+    pub fn dex_id(self, dex_id: u32) -> Self {
+        self.equal(CardField::DexId, dex_id)
+    }
+
+    /// Keep Pokémon with this retreat cost
+    pub fn retreat(self, retreat: u32) -> Self {
+        self.equal(CardField::Retreat, retreat)
+    }
+
+    /// Keep cards worth at least this price, in the currency of the price field
+    pub fn price_gte(self, field: PriceField, price: f64) -> Self {
+        self.greater_or_equal_than(field.into(), price)
+    }
+
+    /// Keep cards worth at most this price, in the currency of the price field
+    pub fn price_lte(self, field: PriceField, price: f64) -> Self {
+        self.less_or_equal_than(field.into(), price)
+    }
+
+    /// Keep cards with this regulation mark
+    pub fn regulation_mark(self, mark: impl fmt::Display) -> Self {
+        self.equal(CardField::RegulationMark, mark)
+    }
+
+    /// Keep cards usable, or not, in the standard format
+    pub fn legal_standard(self, legal: bool) -> Self {
+        self.equal(CardField::LegalStandard, legal)
+    }
+
+    /// Keep cards usable, or not, in the expanded format
+    pub fn legal_expanded(self, legal: bool) -> Self {
+        self.equal(CardField::LegalExpanded, legal)
+    }
+
+    // Name a localized value in the language of the endpoint
+    fn localized(&self, value: impl Localized) -> String {
+        value.localized_name(self.endpoint.language()).to_string()
+    }
+}
+
+impl SetQuery<'_> {
+    /// Keep sets with exactly this name
+    pub fn name(self, name: impl fmt::Display) -> Self {
+        self.equal(SetField::Name, name)
+    }
+
+    /// Keep sets whose name contains the text
+    pub fn name_contains(self, text: impl fmt::Display) -> Self {
+        self.contains(SetField::Name, text)
+    }
+
+    /// Keep sets of the serie with this ID
+    pub fn serie(self, serie_id: impl fmt::Display) -> Self {
+        self.equal(SetField::SerieId, serie_id)
+    }
+
+    /// Keep sets usable, or not, in the standard format
+    pub fn legal_standard(self, legal: bool) -> Self {
+        self.equal(SetField::LegalStandard, legal)
+    }
+
+    /// Keep sets usable, or not, in the expanded format
+    pub fn legal_expanded(self, legal: bool) -> Self {
+        self.equal(SetField::LegalExpanded, legal)
+    }
+
+    /// Keep sets released after the date, excluded
+    pub fn released_after(self, date: Date) -> Self {
+        self.greater_than(SetField::ReleaseDate, date)
+    }
+
+    /// Keep sets released before the date, excluded
+    pub fn released_before(self, date: Date) -> Self {
+        self.less_than(SetField::ReleaseDate, date)
+    }
+
+    /// Keep sets released between the dates, both included
+    pub fn released_between(self, from: Date, to: Date) -> Self {
+        self.greater_or_equal_than(SetField::ReleaseDate, from)
+            .less_or_equal_than(SetField::ReleaseDate, to)
+    }
+}
+
+impl SerieQuery<'_> {
+    /// Keep series with exactly this name
+    pub fn name(self, name: impl fmt::Display) -> Self {
+        self.equal(SerieField::Name, name)
+    }
+
+    /// Keep series whose name contains the text
+    pub fn name_contains(self, text: impl fmt::Display) -> Self {
+        self.contains(SerieField::Name, text)
+    }
+
+    /// Keep series released after the date, excluded
+    pub fn released_after(self, date: Date) -> Self {
+        self.greater_than(SerieField::ReleaseDate, date)
+    }
+
+    /// Keep series released before the date, excluded
+    pub fn released_before(self, date: Date) -> Self {
+        self.less_than(SerieField::ReleaseDate, date)
+    }
+
+    /// Keep series released between the dates, both included
+    pub fn released_between(self, from: Date, to: Date) -> Self {
+        self.greater_or_equal_than(SerieField::ReleaseDate, from)
+            .less_or_equal_than(SerieField::ReleaseDate, to)
+    }
+}
+
+impl Endpoint<Card, CardResume> {
+    /// Start a query only accepting card fields
+    pub fn query(&self) -> CardQuery<'_> {
+        ResourceQuery::new(self)
+    }
+}
+
+impl Endpoint<Set, SetResume> {
+    /// Start a query only accepting set fields
+    pub fn query(&self) -> SetQuery<'_> {
+        ResourceQuery::new(self)
+    }
+}
+
+impl Endpoint<Serie, SerieResume> {
+    /// Start a query only accepting serie fields
+    pub fn query(&self) -> SerieQuery<'_> {
+        ResourceQuery::new(self)
+    }
+}
@@ -0,0 +1,95 @@
+//! Retry policy for API requests
+
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+
+/// Retry policy applied to every request made by the SDK
+///
+/// Failed requests are retried with an exponential backoff: the n-th retry waits
+/// `base_delay * 2^(n - 1)`, capped at `max_delay`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one (1 disables retries)
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Upper bound for the delay between two attempts
+    pub max_delay: Duration,
+    /// Randomize delays so concurrent clients do not retry in lockstep
+    pub jitter: bool,
+    /// HTTP status codes that trigger a retry
+    pub retryable_statuses: Vec<u16>,
+    /// Wait for the duration given by the `Retry-After` header when present
+    ///
+    /// A server asking to wait longer than `max_delay` stops the retries.
+    pub respect_retry_after: bool,
+}
+
+impl RetryPolicy {
+    /// Create a policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Check if a response with the given status should be retried
+    pub fn is_retryable_status(&self, status: u16) -> bool {
+        self.retryable_statuses.contains(&status)
+    }
+
+    /// Compute the delay to wait after the given failed attempt (starting at 1)
+    ///
+    /// Returns `None` when the server asked to wait longer than `max_delay`.
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if self.respect_retry_after {
+            if let Some(retry_after) = retry_after {
+                return (retry_after <= self.max_delay).then_some(retry_after);
+            }
+        }
+
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        if self.jitter {
+            // Equal jitter: keep half of the delay and randomize the other half
+            let half = delay / 2;
+            Some(half + half.mul_f64(fastrand::f64()))
+        } else {
+            Some(delay)
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+            retryable_statuses: vec![408, 429, 500, 502, 503, 504],
+            respect_retry_after: true,
+        }
+    }
+}
+
+/// Parse the `Retry-After` header, given either as seconds or as an HTTP date
+pub(crate) fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(std::time::SystemTime::now())
+            .unwrap_or_default(),
+    )
+}
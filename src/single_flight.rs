@@ -0,0 +1,78 @@
+//! Deduplication of concurrent identical requests
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+
+use futures::future::{BoxFuture, FutureExt, Shared};
+
+use crate::error::{Error, Result};
+
+/// Result of a call, shared by every caller waiting for it
+type SharedResult<T> = std::result::Result<T, Arc<Error>>;
+
+/// Calls in flight by key
+type Calls<T> = HashMap<String, Shared<BoxFuture<'static, SharedResult<T>>>>;
+
+/// Calls in flight by key, sharing one future between concurrent callers
+pub(crate) struct SingleFlight<T> {
+    /// Calls in flight, removed once they complete
+    calls: Mutex<Calls<T>>,
+}
+
+impl<T> SingleFlight<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Create an empty set of calls
+    pub(crate) fn new() -> Self {
+        Self {
+            calls: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Run the call for the given key, or wait for the same call already in flight
+    ///
+    /// Every caller gets a copy of the same result, see [`Error::duplicate`].
+    pub(crate) async fn run<F>(self: &Arc<Self>, key: &str, call: impl FnOnce() -> F) -> Result<T>
+    where
+        F: Future<Output = Result<T>> + Send + 'static,
+    {
+        let shared = {
+            let mut calls = self.lock();
+            match calls.get(key) {
+                Some(shared) => shared.clone(),
+                None => {
+                    let call = call();
+                    let calls_ref = Arc::downgrade(self);
+                    let owned_key = key.to_string();
+
+                    let shared = async move {
+                        let result = call.await.map_err(Arc::new);
+                        forget(&calls_ref, &owned_key);
+                        result
+                    }
+                    .boxed()
+                    .shared();
+                    calls.insert(key.to_string(), shared.clone());
+                    shared
+                }
+            }
+        };
+
+        // Every waiter gets a copy, so the error does not depend on who finished last
+        shared.await.map_err(|err| err.duplicate())
+    }
+
+    // Lock the calls, recovering from a poisoned mutex since calls stay consistent
+    fn lock(&self) -> MutexGuard<'_, Calls<T>> {
+        self.calls.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+// Remove a completed call, so the next caller sends a new request
+fn forget<T: Clone + Send + Sync + 'static>(calls: &Weak<SingleFlight<T>>, key: &str) {
+    if let Some(calls) = calls.upgrade() {
+        calls.lock().remove(key);
+    }
+}
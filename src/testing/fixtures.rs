@@ -0,0 +1,93 @@
+//! Canned API responses bundled with the SDK
+//!
+//! The fixtures mirror real TCGdex payloads and are served by
+//! [`MockTransport::with_fixtures`](super::MockTransport::with_fixtures).
+
+/// Charizard from Base Set (`base1-4`) in English
+pub const CARD_BASE1_4_EN: &str = include_str!("fixtures/card_base1-4_en.json");
+/// Charizard from Base Set (`base1-4`) in French
+pub const CARD_BASE1_4_FR: &str = include_str!("fixtures/card_base1-4_fr.json");
+/// Charizard from Base Set (`base1-4`) in German
+pub const CARD_BASE1_4_DE: &str = include_str!("fixtures/card_base1-4_de.json");
+/// Pineco from Scarlet & Violet (`sv1-1`) in English, found in every booster
+pub const CARD_SV1_1_EN: &str = include_str!("fixtures/card_sv1-1_en.json");
+/// Forretress ex from Scarlet & Violet (`sv1-2`) in English, found in the Koraidon booster
+pub const CARD_SV1_2_EN: &str = include_str!("fixtures/card_sv1-2_en.json");
+/// Scyther from Scarlet & Violet (`sv1-3`) in English, found in the Miraidon booster
+pub const CARD_SV1_3_EN: &str = include_str!("fixtures/card_sv1-3_en.json");
+/// Cards whose name contains "Pikachu"
+pub const CARDS_PIKACHU: &str = include_str!("fixtures/cards_pikachu.json");
+/// Fire cards with at least 100 HP, sorted by name
+pub const CARDS_FIRE_HP_100: &str = include_str!("fixtures/cards_fire_hp_100.json");
+/// Base Set (`base1`)
+pub const SET_BASE1: &str = include_str!("fixtures/set_base1.json");
+/// Scarlet & Violet set (`sv1`), reduced to its first cards
+pub const SET_SV1: &str = include_str!("fixtures/set_sv1.json");
+/// List of sets
+pub const SETS: &str = include_str!("fixtures/sets.json");
+/// Base serie (`base`)
+pub const SERIE_BASE: &str = include_str!("fixtures/serie_base.json");
+/// List of series
+pub const SERIES: &str = include_str!("fixtures/series.json");
+/// Fire type with its cards
+pub const TYPE_FIRE: &str = include_str!("fixtures/type_fire.json");
+/// List of types
+pub const TYPES: &str = include_str!("fixtures/types.json");
+/// List of rarities
+pub const RARITIES: &str = include_str!("fixtures/rarities.json");
+/// List of categories
+pub const CATEGORIES: &str = include_str!("fixtures/categories.json");
+/// List of stages
+pub const STAGES: &str = include_str!("fixtures/stages.json");
+/// List of suffixes
+pub const SUFFIXES: &str = include_str!("fixtures/suffixes.json");
+/// List of trainer types
+pub const TRAINER_TYPES: &str = include_str!("fixtures/trainer_types.json");
+/// List of energy types
+pub const ENERGY_TYPES: &str = include_str!("fixtures/energy_types.json");
+/// List of regulation marks
+pub const REGULATION_MARKS: &str = include_str!("fixtures/regulation_marks.json");
+/// List of variants
+pub const VARIANTS: &str = include_str!("fixtures/variants.json");
+/// List of illustrators
+pub const ILLUSTRATORS: &str = include_str!("fixtures/illustrators.json");
+/// List of HP values
+pub const HP: &str = include_str!("fixtures/hp.json");
+/// List of retreat costs
+pub const RETREATS: &str = include_str!("fixtures/retreats.json");
+/// List of Pokédex IDs
+pub const DEX_IDS: &str = include_str!("fixtures/dex_ids.json");
+
+/// URL patterns served by the bundled fixtures
+pub const ROUTES: &[(&str, &str)] = &[
+    ("*/en/cards/base1-4", CARD_BASE1_4_EN),
+    ("*/fr/cards/base1-4", CARD_BASE1_4_FR),
+    ("*/de/cards/base1-4", CARD_BASE1_4_DE),
+    ("*/en/cards/sv1-1", CARD_SV1_1_EN),
+    ("*/en/cards/sv1-2", CARD_SV1_2_EN),
+    ("*/en/cards/sv1-3", CARD_SV1_3_EN),
+    ("*/en/cards?name=Pikachu", CARDS_PIKACHU),
+    (
+        "*/en/cards?types=eq%3AFire&hp=gte%3A100&sort%3Afield=name&sort%3Aorder=asc",
+        CARDS_FIRE_HP_100,
+    ),
+    ("*/en/sets/base1", SET_BASE1),
+    ("*/en/sets/sv1", SET_SV1),
+    ("*/en/sets", SETS),
+    ("*/en/series/base", SERIE_BASE),
+    ("*/en/series", SERIES),
+    ("*/en/types/Fire", TYPE_FIRE),
+    ("*/en/types", TYPES),
+    ("*/en/rarities", RARITIES),
+    ("*/en/categories", CATEGORIES),
+    ("*/en/stages", STAGES),
+    ("*/en/suffixes", SUFFIXES),
+    ("*/en/trainer-types", TRAINER_TYPES),
+    ("*/en/energy-types", ENERGY_TYPES),
+    ("*/en/regulation-marks", REGULATION_MARKS),
+    ("*/en/variants", VARIANTS),
+    ("*/en/illustrators", ILLUSTRATORS),
+    ("*/en/hp", HP),
+    ("*/en/retreats", RETREATS),
+    ("*/en/dex-ids", DEX_IDS),
+];
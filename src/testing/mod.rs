@@ -0,0 +1,161 @@
+//! Tools to test code using the SDK without reaching the TCGdex API
+//!
+//! ```
+//! use tcgdex_sdk::testing::MockTransport;
+//! use tcgdex_sdk::{Language, TCGdex};
+//!
+//! # async fn example() {
+//! let mock = MockTransport::with_fixtures();
+//! let tcgdex = TCGdex::with_transport(TCGdex::DEFAULT_ENDPOINT, Language::EN, mock);
+//!
+//! let card = tcgdex.card.get("base1-4").await.unwrap();
+//! assert_eq!(card.name, "Charizard");
+//! # }
+//! ```
+
+pub mod fixtures;
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
+use reqwest::StatusCode;
+
+use crate::error::Result;
+use crate::transport::{Request, Response, Transport};
+
+/// A canned response served by the mock transport
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    /// Response status
+    pub status: StatusCode,
+    /// Response headers
+    pub headers: HeaderMap,
+    /// Response body
+    pub body: Bytes,
+}
+
+impl MockResponse {
+    /// Create a `200 OK` response with a JSON body
+    pub fn json(body: impl Into<Bytes>) -> Self {
+        Self::status(StatusCode::OK)
+            .with_header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
+            .with_body(body)
+    }
+
+    /// Create an empty response with the given status
+    pub fn status(status: StatusCode) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: Bytes::new(),
+        }
+    }
+
+    /// Add a header to the response
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.append(name, value);
+        self
+    }
+
+    /// Replace the response body
+    pub fn with_body(mut self, body: impl Into<Bytes>) -> Self {
+        self.body = body.into();
+        self
+    }
+}
+
+/// A programmable transport serving canned responses
+///
+/// Responses are matched against the full request URL using patterns where `*`
+/// matches any sequence of characters, e.g. `*/en/cards/base1-4`. The most
+/// recently added matching route wins, so routes added after
+/// [`with_fixtures`](Self::with_fixtures) override the bundled fixtures.
+/// Unmatched requests are answered with `404 Not Found`.
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    /// Routes as URL patterns and responses
+    routes: Mutex<Vec<(String, MockResponse)>>,
+    /// Requests received so far
+    requests: Mutex<Vec<Request>>,
+}
+
+impl MockTransport {
+    /// Create a mock transport without any route
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a mock transport serving the bundled fixtures
+    pub fn with_fixtures() -> Self {
+        let mock = Self::new();
+        for (pattern, body) in fixtures::ROUTES {
+            mock.route(*pattern, MockResponse::json(*body));
+        }
+        mock
+    }
+
+    /// Serve the given response for requests matching the URL pattern
+    pub fn route(&self, pattern: impl Into<String>, response: MockResponse) -> &Self {
+        lock(&self.routes).push((pattern.into(), response));
+        self
+    }
+
+    /// Serve a JSON body for requests matching the URL pattern
+    pub fn json(&self, pattern: impl Into<String>, body: impl Into<Bytes>) -> &Self {
+        self.route(pattern, MockResponse::json(body))
+    }
+
+    /// Get the requests received so far
+    pub fn requests(&self) -> Vec<Request> {
+        lock(&self.requests).clone()
+    }
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn send(&self, request: Request) -> Result<Response> {
+        let response = lock(&self.routes)
+            .iter()
+            .rev()
+            .find(|(pattern, _)| matches_pattern(pattern, &request.url))
+            .map(|(_, response)| response.clone())
+            .unwrap_or_else(|| MockResponse::status(StatusCode::NOT_FOUND));
+        lock(&self.requests).push(request);
+
+        Ok(Response {
+            status: response.status,
+            headers: response.headers,
+            body: response.body,
+        })
+    }
+}
+
+/// Check if a URL matches a pattern where `*` matches any sequence of characters
+fn matches_pattern(pattern: &str, url: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = url.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No wildcard, the whole URL must match
+        return rest.is_empty();
+    };
+
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+// Lock a mutex, recovering from poisoning caused by a panicking test
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|err| err.into_inner())
+}
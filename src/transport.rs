@@ -0,0 +1,88 @@
+//! Transport abstraction used to send HTTP requests
+
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+
+use crate::error::Result;
+
+/// An HTTP GET request sent by the SDK
+#[derive(Debug, Clone)]
+pub struct Request {
+    /// Full request URL
+    pub url: String,
+    /// Additional request headers (e.g. cache validators)
+    pub headers: HeaderMap,
+}
+
+/// A response returned by a transport, with its body fully read
+#[derive(Debug, Clone)]
+pub struct Response {
+    /// Response status
+    pub status: StatusCode,
+    /// Response headers
+    pub headers: HeaderMap,
+    /// Response body
+    pub body: Bytes,
+}
+
+/// A way of sending HTTP requests to the API
+///
+/// The SDK applies its own policies (retries, rate limiting, caching) on top of
+/// the transport, so an implementation only has to send a single request and
+/// report any status as a `Response`. Errors are reserved for failures where no
+/// response was received; the ones that may be transient are retried.
+#[async_trait]
+pub trait Transport: Send + Sync + Debug {
+    /// Send a request and read the whole response
+    async fn send(&self, request: Request) -> Result<Response>;
+
+    /// Get the `reqwest` client sending the requests, if the transport uses one
+    fn reqwest_client(&self) -> Option<&reqwest::Client> {
+        None
+    }
+}
+
+/// Default transport based on `reqwest`
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    /// HTTP client for requests
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Create a new transport using the given client
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+
+    /// Get the HTTP client used for requests
+    pub fn client(&self) -> &reqwest::Client {
+        &self.client
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: Request) -> Result<Response> {
+        let response = self
+            .client
+            .get(&request.url)
+            .headers(request.headers)
+            .send()
+            .await?;
+
+        Ok(Response {
+            status: response.status(),
+            headers: response.headers().clone(),
+            body: response.bytes().await?,
+        })
+    }
+
+    fn reqwest_client(&self) -> Option<&reqwest::Client> {
+        Some(&self.client)
+    }
+}
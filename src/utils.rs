@@ -1,11 +1,40 @@
 //! Utility functions for the TCGdex SDK
 
-use crate::error::Result;
-use crate::models::{Extension, Quality};
+use crate::error::{Error, Result};
+use crate::http::HttpClient;
+use crate::models::{Date, Extension, Quality, Timestamp};
 use serde::de::{Error as DeError, Visitor};
-use serde::Deserializer;
+use serde::{Deserialize, Deserializer};
 use std::fmt;
 
+/// Deserialize a date, `None` when it is missing, empty or malformed
+pub fn deserialize_lenient_date<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Date>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(value
+        .as_ref()
+        .and_then(serde_json::Value::as_str)
+        .and_then(|text| text.parse().ok()))
+}
+
+/// Deserialize a timestamp, `None` when it is missing, empty or malformed
+pub fn deserialize_lenient_timestamp<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Timestamp>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(value
+        .as_ref()
+        .and_then(serde_json::Value::as_str)
+        .and_then(|text| text.parse().ok()))
+}
+
 /// Deserialize a value that could be either a string or a number into an i32
 pub fn deserialize_string_or_number_to_i32<'de, D>(
     deserializer: D,
@@ -83,10 +112,16 @@ where
 }
 
 /// Download an image from the given URL
-pub async fn download_image(client: &reqwest::Client, url: &str) -> Result<bytes::Bytes> {
-    let response = client.get(url).send().await?.error_for_status()?;
-    let bytes = response.bytes().await?;
-    Ok(bytes)
+///
+/// A missing image is reported as [`Error::ImageNotFound`], since it has no resource ID.
+pub async fn download_image(http: &HttpClient, url: &str) -> Result<bytes::Bytes> {
+    let response = http.download(url).await?;
+    if response.status == reqwest::StatusCode::NOT_FOUND {
+        return Err(Error::ImageNotFound {
+            url: url.to_string(),
+        });
+    }
+    Ok(response.error_for_status("image", "")?.body)
 }
 
 /// Build a full image URL with the given base URL, quality, and extension
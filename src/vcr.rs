@@ -0,0 +1,204 @@
+//! Record and replay of API interactions
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use bytes::Bytes;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::transport::{Request, Response, Transport};
+
+/// A recorded request and the response it received
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    /// Full request URL
+    url: String,
+    /// Response status
+    status: u16,
+    /// Response headers as name/value pairs
+    headers: Vec<(String, String)>,
+    /// Response body, when it is valid UTF-8
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    /// Response body encoded in base64, for binary content such as images
+    #[serde(
+        default,
+        rename = "bodyBase64",
+        skip_serializing_if = "Option::is_none"
+    )]
+    body_base64: Option<String>,
+}
+
+impl Interaction {
+    /// Record a response to the given URL
+    fn new(url: &str, response: &Response) -> Self {
+        let headers = response
+            .headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let (body, body_base64) = match std::str::from_utf8(&response.body) {
+            Ok(text) => (Some(text.to_string()), None),
+            Err(_) => (None, Some(BASE64.encode(&response.body))),
+        };
+
+        Self {
+            url: url.to_string(),
+            status: response.status.as_u16(),
+            headers,
+            body,
+            body_base64,
+        }
+    }
+
+    /// Rebuild the recorded response
+    fn response(&self) -> Result<Response> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &self.headers {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                headers.append(name, value);
+            }
+        }
+
+        let body = match (&self.body, &self.body_base64) {
+            (_, Some(encoded)) => Bytes::from(
+                BASE64
+                    .decode(encoded)
+                    .map_err(|err| Error::Other(format!("Invalid cassette body: {}", err)))?,
+            ),
+            (Some(text), None) => Bytes::from(text.clone()),
+            (None, None) => Bytes::new(),
+        };
+
+        Ok(Response {
+            status: StatusCode::from_u16(self.status)
+                .map_err(|err| Error::Other(format!("Invalid cassette status: {}", err)))?,
+            headers,
+            body,
+        })
+    }
+}
+
+/// Content of a cassette file
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Cassette {
+    /// Interactions in the order they happened
+    interactions: Vec<Interaction>,
+}
+
+/// Transport recording every interaction of another transport into a cassette file
+///
+/// The cassette is rewritten after each interaction, so it is complete even if
+/// the program stops abruptly.
+#[derive(Debug)]
+pub struct RecordingTransport {
+    /// Transport actually sending the requests
+    inner: Arc<dyn Transport>,
+    /// Path of the cassette file
+    path: PathBuf,
+    /// Interactions recorded so far, locked while the file is written to keep writes ordered
+    cassette: tokio::sync::Mutex<Cassette>,
+}
+
+impl RecordingTransport {
+    /// Record the interactions of `inner` into a new cassette at the given path
+    ///
+    /// An existing cassette at this path is overwritten.
+    pub fn new(inner: Arc<dyn Transport>, path: impl Into<PathBuf>) -> Self {
+        Self {
+            inner,
+            path: path.into(),
+            cassette: tokio::sync::Mutex::new(Cassette::default()),
+        }
+    }
+
+    /// Get the path of the cassette file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[async_trait]
+impl Transport for RecordingTransport {
+    async fn send(&self, request: Request) -> Result<Response> {
+        let url = request.url.clone();
+        let response = self.inner.send(request).await?;
+
+        let mut cassette = self.cassette.lock().await;
+        cassette
+            .interactions
+            .push(Interaction::new(&url, &response));
+        tokio::fs::write(&self.path, serde_json::to_vec_pretty(&*cassette)?).await?;
+
+        Ok(response)
+    }
+
+    fn reqwest_client(&self) -> Option<&reqwest::Client> {
+        self.inner.reqwest_client()
+    }
+}
+
+/// Transport serving the interactions of a cassette file instead of reaching the API
+///
+/// Requests are matched by URL, in the order they were recorded: each recorded
+/// interaction is served once, then the last one matching the URL is reused.
+/// A request that was never recorded fails with [`Error::NotRecorded`].
+#[derive(Debug)]
+pub struct ReplayTransport {
+    /// Recorded interactions
+    interactions: Vec<Interaction>,
+    /// Whether each interaction was already served
+    served: Mutex<Vec<bool>>,
+}
+
+impl ReplayTransport {
+    /// Load the cassette at the given path
+    pub fn new(path: impl AsRef<Path>) -> Result<Self> {
+        let cassette: Cassette = serde_json::from_slice(&std::fs::read(path)?)?;
+        let served = vec![false; cassette.interactions.len()];
+
+        Ok(Self {
+            interactions: cassette.interactions,
+            served: Mutex::new(served),
+        })
+    }
+}
+
+#[async_trait]
+impl Transport for ReplayTransport {
+    async fn send(&self, request: Request) -> Result<Response> {
+        let index = {
+            let mut served = lock(&self.served);
+            let matching = || {
+                self.interactions
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, interaction)| interaction.url == request.url)
+                    .map(|(index, _)| index)
+            };
+
+            let index = matching()
+                .find(|index| !served[*index])
+                .or_else(|| matching().next_back())
+                .ok_or_else(|| Error::NotRecorded(request.url.clone()))?;
+            served[index] = true;
+            index
+        };
+
+        self.interactions[index].response()
+    }
+}
+
+// Lock a mutex, recovering from poisoning since the guarded data stays consistent
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|err| err.into_inner())
+}
@@ -0,0 +1,93 @@
+//! Client builder tests
+
+use std::time::Duration;
+
+use tcgdex_sdk::testing::MockTransport;
+use tcgdex_sdk::{Error, Language, TCGdex, TCGdexBuilder};
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SERIE: &str = r#"{"id": "base", "name": "Base", "sets": []}"#;
+
+// Build a client expected to be invalid, returning the error
+fn build_error(builder: TCGdexBuilder) -> Error {
+    match builder.build() {
+        Ok(_) => panic!("configuration should be invalid"),
+        Err(err) => err,
+    }
+}
+
+#[test]
+fn test_defaults() {
+    let tcgdex = TCGdex::builder().build().unwrap();
+
+    assert_eq!(tcgdex.get_endpoint(), TCGdex::DEFAULT_ENDPOINT);
+    assert_eq!(tcgdex.language, Language::EN);
+    assert_eq!(tcgdex.fallback_language(), None);
+    assert!(tcgdex.default_query().is_empty());
+}
+
+#[test]
+fn test_invalid_configuration() {
+    let err = build_error(TCGdex::builder().endpoint("not a url"));
+    assert!(matches!(err, Error::Url(_)));
+
+    let err = build_error(TCGdex::builder().header("bad header", "value"));
+    assert!(matches!(err, Error::Config(_)));
+
+    let err = build_error(TCGdex::builder().proxy("::not a proxy::"));
+    assert!(matches!(err, Error::Http(_)));
+
+    let err = build_error(
+        TCGdex::builder()
+            .client(reqwest::Client::new())
+            .timeout(Duration::from_secs(5)),
+    );
+    assert!(matches!(err, Error::Config(_)));
+}
+
+#[tokio::test]
+async fn test_http_options() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/en/series/base"))
+        .and(header("X-Api-Key", "secret"))
+        .and(header(
+            "User-Agent",
+            format!("tcgdex-rust-sdk/{} my-app/1.0", tcgdex_sdk::VERSION).as_str(),
+        ))
+        .and(query_param("source", "tests"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(SERIE, "application/json"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let tcgdex = TCGdex::builder()
+        .endpoint(format!("{}/", server.uri()))
+        .timeout(Duration::from_secs(5))
+        .connect_timeout(Duration::from_secs(1))
+        .header("X-Api-Key", "secret")
+        .user_agent_suffix("my-app/1.0")
+        .default_query_param("source", "tests")
+        .build()
+        .unwrap();
+
+    let serie = tcgdex.serie.get("base").await.unwrap();
+    assert_eq!(serie.name, "Base");
+}
+
+#[tokio::test]
+async fn test_fallback_language() {
+    let tcgdex = TCGdex::builder()
+        .language(Language::IT)
+        .fallback_language(Language::EN)
+        .transport(MockTransport::with_fixtures())
+        .build()
+        .unwrap();
+
+    let card = tcgdex.card.get("base1-4").await.unwrap();
+    assert_eq!(card.name, "Charizard");
+
+    let err = tcgdex.card.get("unknown-1").await.unwrap_err();
+    assert!(matches!(err, Error::NotFound { .. }));
+}
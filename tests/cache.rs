@@ -0,0 +1,107 @@
+//! In-memory cache tests against a local stub server
+
+use std::time::Duration;
+
+use tcgdex_sdk::{CacheConfig, Language, TCGdex};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_serie(server: &MockServer, id: &str, expected_calls: u64) {
+    let body = format!(r#"{{"id": "{id}", "name": "{id}", "sets": []}}"#);
+    Mock::given(method("GET"))
+        .and(path(format!("/en/series/{id}")))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .expect(expected_calls)
+        .mount(server)
+        .await;
+}
+
+fn client(server: &MockServer, config: CacheConfig) -> TCGdex {
+    let mut tcgdex = TCGdex::with_endpoint(&server.uri(), Language::EN);
+    tcgdex.set_cache(Some(config));
+    tcgdex
+}
+
+#[tokio::test]
+async fn test_cache_hit_and_invalidate() {
+    let server = MockServer::start().await;
+    mount_serie(&server, "base", 2).await;
+    let tcgdex = client(&server, CacheConfig::default());
+
+    tcgdex.serie.get("base").await.unwrap();
+    tcgdex.serie.get("base").await.unwrap();
+    assert_eq!(tcgdex.cache().unwrap().len(), 1);
+
+    assert!(tcgdex.serie.invalidate("base"));
+    tcgdex.serie.get("base").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_clear_shared_cache() {
+    let server = MockServer::start().await;
+    mount_serie(&server, "base", 2).await;
+    let tcgdex = std::sync::Arc::new(client(&server, CacheConfig::default()));
+
+    tcgdex.serie.get("base").await.unwrap();
+    tcgdex.clear_cache();
+    assert!(tcgdex.cache().unwrap().is_empty());
+    tcgdex.serie.get("base").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_cache_ttl() {
+    let server = MockServer::start().await;
+    mount_serie(&server, "base", 2).await;
+    let tcgdex = client(
+        &server,
+        CacheConfig {
+            ttl: Duration::from_millis(50),
+            ..CacheConfig::default()
+        },
+    );
+
+    tcgdex.serie.get("base").await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    tcgdex.serie.get("base").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_cache_without_expiry() {
+    let server = MockServer::start().await;
+    mount_serie(&server, "base", 1).await;
+    let tcgdex = client(
+        &server,
+        CacheConfig {
+            ttl: Duration::MAX,
+            ..CacheConfig::default()
+        },
+    );
+
+    tcgdex.serie.get("base").await.unwrap();
+    tcgdex.serie.get("base").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_cache_lru_eviction() {
+    let server = MockServer::start().await;
+    mount_serie(&server, "base", 1).await;
+    mount_serie(&server, "gym", 2).await;
+    mount_serie(&server, "neo", 1).await;
+    let tcgdex = client(
+        &server,
+        CacheConfig {
+            max_entries: 2,
+            ..CacheConfig::default()
+        },
+    );
+
+    tcgdex.serie.get("base").await.unwrap();
+    tcgdex.serie.get("gym").await.unwrap();
+    // Touch base so gym becomes the least recently used entry
+    tcgdex.serie.get("base").await.unwrap();
+    tcgdex.serie.get("neo").await.unwrap();
+
+    assert_eq!(tcgdex.cache().unwrap().len(), 2);
+    tcgdex.serie.get("base").await.unwrap();
+    tcgdex.serie.get("gym").await.unwrap();
+}
@@ -0,0 +1,113 @@
+//! Deduplication of concurrent identical requests, against a local stub server
+
+use std::time::Duration;
+
+use futures::future::join_all;
+use tcgdex_sdk::{Error, Language, RetryPolicy, TCGdex};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SERIE: &str = r#"{"id": "base", "name": "Base", "sets": []}"#;
+const SERIES: &str = r#"[{"id": "base", "name": "Base"}]"#;
+
+fn client(server: &MockServer) -> TCGdex {
+    let mut tcgdex = TCGdex::with_endpoint(&server.uri(), Language::EN);
+    tcgdex.set_retry_policy(RetryPolicy::none());
+    tcgdex
+}
+
+fn delayed(status: u16, body: &str) -> ResponseTemplate {
+    ResponseTemplate::new(status)
+        .set_body_raw(body, "application/json")
+        .set_delay(Duration::from_millis(200))
+}
+
+#[tokio::test]
+async fn test_concurrent_gets_share_request() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/en/series/base"))
+        .respond_with(delayed(200, SERIE))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let tcgdex = client(&server);
+
+    let series = join_all((0..10).map(|_| tcgdex.serie.get("base"))).await;
+    assert!(series
+        .iter()
+        .all(|serie| serie.as_ref().unwrap().name == "Base"));
+}
+
+#[tokio::test]
+async fn test_concurrent_lists_share_request() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/en/series"))
+        .respond_with(delayed(200, SERIES))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let tcgdex = client(&server);
+
+    let lists = join_all((0..10).map(|_| tcgdex.serie.list(None))).await;
+    assert!(lists.iter().all(|list| list.as_ref().unwrap().len() == 1));
+}
+
+#[tokio::test]
+async fn test_concurrent_errors_are_shared() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/en/series/unknown"))
+        .respond_with(delayed(404, ""))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let tcgdex = client(&server);
+
+    let results = join_all((0..5).map(|_| tcgdex.serie.get("unknown"))).await;
+    for result in results {
+        assert!(matches!(
+            result,
+            Err(Error::NotFound { resource, id }) if resource == "series" && id == "unknown"
+        ));
+    }
+}
+
+#[tokio::test]
+async fn test_concurrent_http_errors_have_one_variant() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/en/series/base"))
+        .respond_with(delayed(200, SERIE))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let mut tcgdex = TCGdex::builder()
+        .endpoint(server.uri())
+        .timeout(Duration::from_millis(50))
+        .build()
+        .unwrap();
+    tcgdex.set_retry_policy(RetryPolicy::none());
+
+    // Every waiter gets the same variant, whichever finished last
+    let results = join_all((0..5).map(|_| tcgdex.serie.get("base"))).await;
+    for result in results {
+        assert!(matches!(result, Err(Error::Transport(_))), "{:?}", result);
+    }
+}
+
+#[tokio::test]
+async fn test_sequential_gets_are_not_shared() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/en/series/base"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(SERIE, "application/json"))
+        .expect(2)
+        .mount(&server)
+        .await;
+    let tcgdex = client(&server);
+
+    tcgdex.serie.get("base").await.unwrap();
+    tcgdex.serie.get("base").await.unwrap();
+}
@@ -0,0 +1,134 @@
+//! Disk cache tests against a local stub server
+
+use tcgdex_sdk::{DiskCache, Language, TCGdex};
+use wiremock::matchers::{header, header_exists, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SERIE: &str = r#"{"id": "base", "name": "Base", "sets": []}"#;
+
+fn client(server: &MockServer, dir: &tempfile::TempDir) -> TCGdex {
+    let mut tcgdex = TCGdex::with_endpoint(&server.uri(), Language::EN);
+    tcgdex.set_disk_cache(Some(DiskCache::new(dir.path()).unwrap()));
+    tcgdex
+}
+
+#[tokio::test]
+async fn test_disk_cache_revalidation() {
+    let server = MockServer::start().await;
+    let dir = tempfile::tempdir().unwrap();
+
+    // Revalidation with the stored ETag is answered with 304 Not Modified
+    Mock::given(method("GET"))
+        .and(path("/en/series/base"))
+        .and(header("If-None-Match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/en/series/base"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", "\"v1\"")
+                .set_body_raw(SERIE, "application/json"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let serie = client(&server, &dir).serie.get("base").await.unwrap();
+    assert_eq!(serie.name, "Base");
+
+    // A new client simulates a restart, the cached body must survive it
+    let serie = client(&server, &dir).serie.get("base").await.unwrap();
+    assert_eq!(serie.name, "Base");
+}
+
+#[tokio::test]
+async fn test_disk_cache_updated_resource() {
+    let server = MockServer::start().await;
+    let dir = tempfile::tempdir().unwrap();
+    let tcgdex = client(&server, &dir);
+
+    Mock::given(method("GET"))
+        .and(path("/en/series/base"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Last-Modified", "Wed, 21 Oct 2015 07:28:00 GMT")
+                .set_body_raw(SERIE, "application/json"),
+        )
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    tcgdex.serie.get("base").await.unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/en/series/base"))
+        .and(header_exists("If-Modified-Since"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{"id": "base", "name": "Base Updated", "sets": []}"#,
+            "application/json",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let serie = tcgdex.serie.get("base").await.unwrap();
+    assert_eq!(serie.name, "Base Updated");
+
+    // The update had no validators, so the stale ones are not sent again
+    Mock::given(method("GET"))
+        .and(path("/en/series/base"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(SERIE, "application/json"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let serie = tcgdex.serie.get("base").await.unwrap();
+    assert_eq!(serie.name, "Base");
+
+    tcgdex.disk_cache().unwrap().clear().unwrap();
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+}
+
+#[tokio::test]
+async fn test_disk_cache_clear_keeps_foreign_files() {
+    let server = MockServer::start().await;
+    let dir = tempfile::tempdir().unwrap();
+    let tcgdex = client(&server, &dir);
+
+    Mock::given(method("GET"))
+        .and(path("/en/series/base"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", "\"v1\"")
+                .set_body_raw(SERIE, "application/json"),
+        )
+        .mount(&server)
+        .await;
+    tcgdex.serie.get("base").await.unwrap();
+
+    let foreign = [
+        "config.json",
+        "notes.body",
+        "draft.tmp",
+        "0123456789abcdef.txt",
+    ];
+    for name in foreign {
+        std::fs::write(dir.path().join(name), "{}").unwrap();
+    }
+    tcgdex.disk_cache().unwrap().clear().unwrap();
+
+    let mut remaining: Vec<_> = std::fs::read_dir(dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    remaining.sort();
+    assert_eq!(
+        remaining,
+        [
+            "0123456789abcdef.txt",
+            "config.json",
+            "draft.tmp",
+            "notes.body"
+        ]
+    );
+}
@@ -3,9 +3,10 @@
 //! These tests make real API requests and are marked with #[ignore] by default
 //! To run these tests: cargo test --test integration -- --ignored
 
-use tcgdex_sdk::{Language, Query, TCGdex};
+use tcgdex_sdk::{Language, PokemonType, Query, TCGdex};
 
 #[tokio::test]
+#[ignore]
 async fn test_get_card() {
     let tcgdex = TCGdex::new(Language::EN);
 
@@ -17,6 +18,7 @@ async fn test_get_card() {
 }
 
 #[tokio::test]
+#[ignore]
 async fn test_card_list() {
     let tcgdex = TCGdex::new(Language::EN);
 
@@ -33,6 +35,7 @@ async fn test_card_list() {
 }
 
 #[tokio::test]
+#[ignore]
 async fn test_set() {
     let tcgdex = TCGdex::new(Language::EN);
 
@@ -43,6 +46,7 @@ async fn test_set() {
 }
 
 #[tokio::test]
+#[ignore]
 async fn test_serie() {
     let tcgdex = TCGdex::new(Language::EN);
 
@@ -53,6 +57,7 @@ async fn test_serie() {
 }
 
 #[tokio::test]
+#[ignore]
 async fn test_different_languages() {
     // Test English (default)
     let tcgdex_en = TCGdex::new(Language::EN);
@@ -71,6 +76,7 @@ async fn test_different_languages() {
 }
 
 #[tokio::test]
+#[ignore]
 async fn test_string_endpoints() {
     let tcgdex = TCGdex::new(Language::EN);
 
@@ -78,14 +84,15 @@ async fn test_string_endpoints() {
     let types_list = tcgdex.type_.list(None).await.unwrap();
     assert!(!types_list.is_empty());
 
-    // We expect each StringList to contain various types including "Fire"
-    let has_fire = types_list
-        .iter()
-        .any(|string_list| string_list.contains("Fire"));
-    assert!(has_fire, "Should find 'Fire' type in the results");
+    // We expect the types to include Fire
+    assert!(
+        types_list.contains(&PokemonType::Fire),
+        "Should find 'Fire' type in the results"
+    );
 }
 
 #[tokio::test]
+#[ignore]
 async fn test_complex_query() {
     let tcgdex = TCGdex::new(Language::EN);
 
@@ -108,6 +115,7 @@ async fn test_complex_query() {
 }
 
 #[tokio::test]
+#[ignore]
 async fn test_card_image_url() {
     let tcgdex = TCGdex::new(Language::EN);
 
@@ -0,0 +1,557 @@
+//! Offline versions of the integration tests, served by the bundled fixtures
+
+use std::time::Duration;
+use tcgdex_sdk::testing::{MockResponse, MockTransport};
+
+use reqwest::header::{HeaderValue, RETRY_AFTER};
+use reqwest::StatusCode;
+use tcgdex_sdk::{
+    CardField, Category, DamageValue, Date, EnergyType, Error, Extension, Language, PokemonType,
+    PriceField, Quality, Query, Rarity, RetryPolicy, SerieField, SortOrder, Stage, TCGdex,
+    Timestamp,
+};
+
+fn client(language: Language) -> TCGdex {
+    TCGdex::with_transport(
+        TCGdex::DEFAULT_ENDPOINT,
+        language,
+        MockTransport::with_fixtures(),
+    )
+}
+
+#[tokio::test]
+async fn test_get_card() {
+    let tcgdex = client(Language::EN);
+
+    let card = tcgdex.card.get("base1-4").await.unwrap();
+    assert_eq!(card.name, "Charizard");
+    assert_eq!(card.hp, Some(120));
+    assert!(card.image.is_some());
+}
+
+#[tokio::test]
+async fn test_card_list() {
+    let tcgdex = client(Language::EN);
+
+    let mut query = Query::new();
+    query.contains("name", "Pikachu");
+    let cards = tcgdex.card.list(Some(&query)).await.unwrap();
+
+    assert!(!cards.is_empty());
+    for card in cards {
+        assert!(card.name.contains("Pikachu"));
+    }
+}
+
+#[tokio::test]
+async fn test_set() {
+    let tcgdex = client(Language::EN);
+
+    let set = tcgdex.set.get("base1").await.unwrap();
+    assert_eq!(set.name, "Base Set");
+    assert!(!set.cards.is_empty());
+}
+
+#[tokio::test]
+async fn test_serie() {
+    let tcgdex = client(Language::EN);
+
+    let serie = tcgdex.serie.get("base").await.unwrap();
+    assert_eq!(serie.name, "Base");
+    assert!(!serie.sets.is_empty());
+    assert_eq!(serie.release_date, Date::new(1999, 1, 9));
+    assert_eq!(serie.first_set.unwrap().id, "base1");
+    assert_eq!(serie.last_set.unwrap().id, "base5");
+}
+
+#[tokio::test]
+async fn test_attacks() {
+    let card = client(Language::EN).card.get("base1-4").await.unwrap();
+    assert_eq!(card.max_base_damage(), Some(100));
+
+    let fire_spin = &card.attacks.as_ref().unwrap()[0];
+    assert_eq!(fire_spin.damage_value(), Some(DamageValue::fixed(100)));
+    assert_eq!(fire_spin.energy_cost().count(&PokemonType::Fire), 4);
+    assert_eq!(fire_spin.energy_cost().converted_cost(), 4);
+    assert!(fire_spin.can_pay(&vec![PokemonType::Fire; 4]));
+    assert!(!fire_spin.can_pay(&[
+        PokemonType::Fire,
+        PokemonType::Fire,
+        PokemonType::Fire,
+        PokemonType::Colorless
+    ]));
+}
+
+#[tokio::test]
+async fn test_dates() {
+    let tcgdex = client(Language::EN);
+
+    let set = tcgdex.set.get("base1").await.unwrap();
+    assert_eq!(set.release_date, Date::new(1999, 1, 9));
+    let updated: Timestamp = "2025-08-15T19:42:19Z".parse().unwrap();
+    assert_eq!(set.updated, Some(updated));
+
+    let card = tcgdex.card.get("base1-4").await.unwrap();
+    assert_eq!(card.updated, Some(updated));
+    let cardmarket = card.pricing.unwrap().cardmarket.unwrap();
+    assert!(cardmarket.updated.unwrap() < updated);
+
+    let query = tcgdex
+        .set
+        .query()
+        .released_between(
+            Date::new(2023, 1, 1).unwrap(),
+            Date::new(2023, 12, 31).unwrap(),
+        )
+        .build();
+    assert_eq!(
+        query.build(),
+        "?releaseDate=gte%3A2023-01-01&releaseDate=lte%3A2023-12-31"
+    );
+
+    // Dates compare and sort chronologically when evaluated locally
+    let base = tcgdex.serie.get("base").await.unwrap();
+    let mut series: Vec<_> = ["2023-03-31", "1999-01-09", "2020-02-07"]
+        .into_iter()
+        .map(|date| {
+            let mut serie = base.clone();
+            serie.release_date = Some(date.parse().unwrap());
+            serie
+        })
+        .collect();
+    let mut query = Query::new();
+    query
+        .greater_than("releaseDate", Date::new(2000, 1, 1).unwrap())
+        .sort("releaseDate", "asc");
+    query.apply(&mut series);
+    let dates: Vec<_> = series
+        .iter()
+        .map(|serie| serie.release_date.unwrap().to_string())
+        .collect();
+    assert_eq!(dates, ["2020-02-07", "2023-03-31"]);
+
+    // List results with release dates are filtered and sorted the same way
+    let mock = MockTransport::new();
+    mock.json(
+        "*/en/series",
+        r#"[
+            {"id": "sv", "name": "Scarlet & Violet", "releaseDate": "2023-03-31"},
+            {"id": "base", "name": "Base", "releaseDate": "1999-01-09"},
+            {"id": "swsh", "name": "Sword & Shield", "releaseDate": "2020-02-07"},
+            {"id": "tcgp", "name": "Pokémon TCG Pocket"}
+        ]"#,
+    );
+    let tcgdex_mock = TCGdex::with_transport(TCGdex::DEFAULT_ENDPOINT, Language::EN, mock);
+    let mut series = tcgdex_mock.serie.list(None).await.unwrap();
+    let query = tcgdex_mock
+        .serie
+        .query()
+        .released_after(Date::new(2000, 1, 1).unwrap())
+        .sort_by(SerieField::ReleaseDate, SortOrder::Desc)
+        .build();
+    query.apply(&mut series);
+    let ids: Vec<_> = series.iter().map(|serie| serie.id.as_str()).collect();
+    assert_eq!(ids, ["sv", "swsh"]);
+
+    // Malformed dates and timestamps do not prevent getting the set or the card
+    let timestamps = [
+        "\"2025-08-15T21:42:19+02:00\"",
+        "\"2025-08-15T00:38:18.000Z\"",
+        "\"2025-08-15T20:06:48.000Z\"",
+    ];
+    for malformed in ["", "1999/01/09", "2025-08-15 noon"] {
+        let malformed = format!("{:?}", malformed);
+        let mut set =
+            tcgdex_sdk::testing::fixtures::SET_BASE1.replace("\"1999-01-09\"", &malformed);
+        let mut card = tcgdex_sdk::testing::fixtures::CARD_BASE1_4_EN.to_string();
+        for timestamp in timestamps {
+            set = set.replace(timestamp, &malformed);
+            card = card.replace(timestamp, &malformed);
+        }
+        let mock = MockTransport::new();
+        mock.json("*/en/sets/base1", set)
+            .json("*/en/cards/base1-4", card);
+        let tcgdex = TCGdex::with_transport(TCGdex::DEFAULT_ENDPOINT, Language::EN, mock);
+
+        let set = tcgdex.set.get("base1").await.unwrap();
+        assert_eq!(set.release_date, None);
+        assert_eq!(set.updated, None);
+        let card = tcgdex.card.get("base1-4").await.unwrap();
+        assert_eq!(card.updated, None);
+        let pricing = card.pricing.unwrap();
+        assert_eq!(pricing.cardmarket.unwrap().updated, None);
+        assert_eq!(pricing.tcgplayer.unwrap().updated, None);
+    }
+}
+
+#[tokio::test]
+async fn test_different_languages() {
+    let card_en = client(Language::EN).card.get("base1-4").await.unwrap();
+    assert_eq!(card_en.name, "Charizard");
+
+    let card_fr = client(Language::FR).card.get("base1-4").await.unwrap();
+    assert_eq!(card_fr.name, "Dracaufeu");
+
+    let card_de = client(Language::DE).card.get("base1-4").await.unwrap();
+    assert_eq!(card_de.name, "Glurak");
+}
+
+#[tokio::test]
+async fn test_localized_types() {
+    let card_fr = client(Language::FR).card.get("base1-4").await.unwrap();
+    assert_eq!(card_fr.types, Some(vec![PokemonType::Fire]));
+    let weaknesses = card_fr.weaknesses.clone().unwrap();
+    assert_eq!(weaknesses[0].type_, PokemonType::Water);
+    assert_eq!(weaknesses[0].type_.localized_name(Language::FR), "Eau");
+
+    let card_de = client(Language::DE).card.get("base1-4").await.unwrap();
+    let cost = card_de.attacks.clone().unwrap()[0].cost.clone().unwrap();
+    assert!(cost.iter().all(|type_| *type_ == PokemonType::Fire));
+
+    // Queries send types in the language of the endpoint
+    let query = client(Language::FR)
+        .card
+        .query()
+        .types_include(PokemonType::Fire)
+        .build();
+    assert_eq!(query.build(), "?types=eq%3AFeu");
+
+    // The French card matches the French query it was fetched with
+    let tcgdex_fr = client(Language::FR);
+    let query = tcgdex_fr
+        .card
+        .query()
+        .types_include(PokemonType::Fire)
+        .rarity(Rarity::Rare)
+        .category(Category::Pokemon)
+        .stage(Stage::Stage2)
+        .equal(CardField::WeaknessType, "Eau")
+        .equal(CardField::ResistanceType, "Combat")
+        .build();
+    assert!(query.matches(&card_fr));
+    assert!(query.matches(&card_de));
+    let mut water = Query::new();
+    water.equal(CardField::Types, "Eau");
+    assert!(!water.matches(&card_fr));
+
+    // Other card properties are parsed in the card language too
+    assert_eq!(card_fr.rarity, Rarity::Rare);
+    assert_eq!(card_fr.category, Category::Pokemon);
+    assert_eq!(card_fr.stage, Some(Stage::Stage2));
+    assert_eq!(card_de.rarity, Rarity::Rare);
+    assert_eq!(card_de.stage, Some(Stage::Stage2));
+
+    let unknown: PokemonType = "Stellar".parse().unwrap();
+    assert_eq!(unknown, PokemonType::Unknown("Stellar".to_string()));
+    assert_eq!(serde_json::to_string(&unknown).unwrap(), r#""Stellar""#);
+}
+
+#[tokio::test]
+async fn test_string_endpoints() {
+    let tcgdex = client(Language::EN);
+
+    let types_list = tcgdex.type_.list(None).await.unwrap();
+    assert!(types_list.contains(&PokemonType::Fire));
+
+    let fire = tcgdex.type_.get_type(&PokemonType::Fire).await.unwrap();
+    assert!(fire.cards.iter().any(|card| card.id == "base1-4"));
+
+    let energy_types = tcgdex.energy_type.list(None).await.unwrap();
+    assert_eq!(energy_types, [EnergyType::Normal, EnergyType::Special]);
+
+    assert!(!tcgdex.rarity.list(None).await.unwrap().is_empty());
+    assert!(!tcgdex.category.list(None).await.unwrap().is_empty());
+    assert!(!tcgdex.illustrator.list(None).await.unwrap().is_empty());
+    assert!(tcgdex
+        .hp
+        .list(None)
+        .await
+        .unwrap()
+        .iter()
+        .any(|hp| hp.contains(120)));
+    assert!(tcgdex
+        .retreat
+        .list(None)
+        .await
+        .unwrap()
+        .iter()
+        .any(|retreat| retreat.contains(3)));
+}
+
+#[tokio::test]
+async fn test_complex_query() {
+    let tcgdex = client(Language::EN);
+
+    let mut query = Query::new();
+    query
+        .equal("types", "Fire")
+        .greater_or_equal_than("hp", 100)
+        .sort("name", "asc");
+
+    let card_resumes = tcgdex.card.list(Some(&query)).await.unwrap();
+    assert!(!card_resumes.is_empty());
+
+    let card = card_resumes[0].get_full_card(&tcgdex).await.unwrap();
+    assert!(card.hp.unwrap_or(0) >= 100, "Card HP should be >= 100");
+}
+
+#[tokio::test]
+async fn test_card_image() {
+    let mock = MockTransport::with_fixtures();
+    mock.route(
+        "*/en/base/base1/4/high.png",
+        MockResponse::status(reqwest::StatusCode::OK).with_body(&b"\x89PNG"[..]),
+    );
+    let tcgdex = TCGdex::with_transport(TCGdex::DEFAULT_ENDPOINT, Language::EN, mock);
+
+    let card = tcgdex.card.get("base1-4").await.unwrap();
+    let image = card
+        .get_image(&tcgdex, Quality::HIGH, Extension::PNG)
+        .await
+        .unwrap();
+    assert_eq!(image.as_deref(), Some(&b"\x89PNG"[..]));
+
+    let err = card
+        .get_image(&tcgdex, Quality::LOW, Extension::PNG)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::ImageNotFound { url } if url == "https://assets.tcgdex.net/en/base/base1/4/low.png"
+    ));
+}
+
+#[tokio::test]
+async fn test_unmatched_request() {
+    let tcgdex = client(Language::EN);
+
+    let err = tcgdex.card.get("unknown-1").await.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::NotFound { resource, id } if resource == "cards" && id == "unknown-1"
+    ));
+}
+
+// Client answering every request with the given response, without retrying
+fn failing_client(response: MockResponse) -> TCGdex {
+    let mock = MockTransport::new();
+    mock.route("*", response);
+    let mut tcgdex = TCGdex::with_transport(TCGdex::DEFAULT_ENDPOINT, Language::EN, mock);
+    tcgdex.set_retry_policy(RetryPolicy::none());
+    tcgdex
+}
+
+#[tokio::test]
+async fn test_server_error() {
+    let tcgdex = failing_client(
+        MockResponse::status(StatusCode::SERVICE_UNAVAILABLE).with_body("maintenance"),
+    );
+
+    let err = tcgdex.serie.list(None).await.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Server { status: 503, body } if body == "maintenance"
+    ));
+}
+
+#[tokio::test]
+async fn test_rate_limited() {
+    let tcgdex = failing_client(
+        MockResponse::status(StatusCode::TOO_MANY_REQUESTS)
+            .with_header(RETRY_AFTER, HeaderValue::from_static("30")),
+    );
+
+    let err = tcgdex.card.get("base1-4").await.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::RateLimited { retry_after: Some(delay) } if delay == Duration::from_secs(30)
+    ));
+}
+
+#[tokio::test]
+async fn test_decode_error() {
+    let tcgdex = failing_client(MockResponse::json("<html>oops</html>"));
+
+    let err = tcgdex.card.get("base1-4").await.unwrap_err();
+    match err {
+        Error::Decode {
+            url, body_snippet, ..
+        } => {
+            assert!(url.ends_with("/en/cards/base1-4"));
+            assert_eq!(body_snippet, "<html>oops</html>");
+        }
+        err => panic!("unexpected error: {err}"),
+    }
+}
+
+#[tokio::test]
+async fn test_get_many() {
+    let tcgdex = client(Language::EN);
+
+    let cards = tcgdex
+        .card
+        .get_many(["base1-4", "unknown-1", "base1-4"], 2)
+        .await;
+
+    assert_eq!(cards.len(), 3);
+    assert_eq!(cards[0].as_ref().unwrap().name, "Charizard");
+    assert!(matches!(cards[1], Err(Error::NotFound { .. })));
+    assert_eq!(cards[2].as_ref().unwrap().name, "Charizard");
+}
+
+#[tokio::test]
+async fn test_set_fetch_all_cards() {
+    let tcgdex = client(Language::EN);
+
+    let set = tcgdex.set.get("base1").await.unwrap();
+    let cards = set.fetch_all_cards(&tcgdex).await;
+
+    // 102 normal, 102 first edition and 16 holo cards, without secret rares
+    assert_eq!(set.card_count.first_ed, Some(102));
+    assert_eq!(set.card_count.secret_rare_count(), 0);
+    assert_eq!(set.card_count.master_set_total(), Some(220));
+
+    // Only Charizard is bundled in the fixtures
+    assert_eq!(cards.len(), set.cards.len());
+    assert_eq!(cards[3].as_ref().unwrap().id, "base1-4");
+    assert!(cards
+        .iter()
+        .enumerate()
+        .all(|(index, card)| (index == 3) == card.is_ok()));
+}
+
+#[tokio::test]
+async fn test_typed_query() {
+    let tcgdex = client(Language::EN);
+
+    let query = tcgdex
+        .card
+        .query()
+        .types_include("Fire")
+        .hp_gte(100)
+        .sort_by(CardField::Name, SortOrder::Asc);
+    let cards = query.list().await.unwrap();
+    assert!(!cards.is_empty());
+
+    let query = query.build();
+    assert_eq!(
+        query.build(),
+        "?types=eq%3AFire&hp=gte%3A100&sort%3Afield=name&sort%3Aorder=asc"
+    );
+}
+
+#[tokio::test]
+async fn test_query_alternatives_are_merged() {
+    let mock = MockTransport::new();
+    mock.json(
+        "*/en/cards?types=eq%3AFire",
+        r#"[{"id": "base1-4", "localId": "4", "name": "Charizard"},
+            {"id": "base1-23", "localId": "23", "name": "Arcanine"}]"#,
+    )
+    .json(
+        "*/en/cards?hp=gte%3A120",
+        r#"[{"id": "base1-4", "localId": "4", "name": "Charizard"},
+            {"id": "base1-2", "localId": "2", "name": "Blastoise"}]"#,
+    );
+    let tcgdex = TCGdex::with_transport(TCGdex::DEFAULT_ENDPOINT, Language::EN, mock);
+
+    let query = tcgdex.card.query().any_of([
+        tcgdex.card.query().types_include("Fire"),
+        tcgdex.card.query().hp_gte(120),
+    ]);
+    let cards = query.list().await.unwrap();
+
+    let ids: Vec<_> = cards.iter().map(|card| card.id.as_str()).collect();
+    assert_eq!(ids, ["base1-4", "base1-23", "base1-2"]);
+
+    // The API would sort and paginate each request instead of the merged list
+    let query = query.sort_by(CardField::Name, SortOrder::Asc).page(1, 20);
+    let err = query.list().await.unwrap_err();
+    assert!(matches!(err, Error::InvalidQuery(_)));
+    let items: Vec<_> = futures::StreamExt::collect(query.stream()).await;
+    assert!(matches!(items.as_slice(), [Err(Error::InvalidQuery(_))]));
+}
+
+#[tokio::test]
+async fn test_card_pricing() {
+    let card = client(Language::EN).card.get("base1-4").await.unwrap();
+    let pricing = card.pricing.clone().unwrap();
+
+    let cardmarket = pricing.cardmarket.unwrap();
+    assert_eq!(cardmarket.currency.as_deref(), Some("EUR"));
+    assert_eq!(cardmarket.trend, Some(392.62));
+    assert_eq!(cardmarket.avg_holo, None);
+
+    let tcgplayer = pricing.tcgplayer.unwrap();
+    assert_eq!(tcgplayer.currency.as_deref(), Some("USD"));
+    assert!(tcgplayer.normal.is_none());
+    assert_eq!(tcgplayer.holofoil.unwrap().market_price, Some(421.37));
+
+    // Marketplace blocks missing their metadata still decode
+    let partial: tcgdex_sdk::CardmarketPricing = serde_json::from_str(r#"{"avg": 1.5}"#).unwrap();
+    assert_eq!(partial.currency, None);
+    assert_eq!(partial.updated, None);
+
+    // Price filters work on the API and locally
+    let tcgdex = client(Language::EN);
+    let query = tcgdex
+        .card
+        .query()
+        .price_gte(PriceField::CardmarketTrend, 100.0)
+        .price_lte(PriceField::TcgplayerHolofoilMarket, 500.5)
+        .build();
+    assert_eq!(
+        query.build(),
+        "?pricing.cardmarket.trend=gte%3A100&pricing.tcgplayer.holofoil.marketPrice=lte%3A500.5"
+    );
+    assert!(query.matches(&card));
+
+    let mut query = Query::new();
+    query.greater_than(CardField::TcgplayerNormalMarket, 1);
+    assert!(!query.matches(&card));
+}
+
+#[tokio::test]
+async fn test_boosters() {
+    let tcgdex = client(Language::EN);
+
+    let set = tcgdex.set.get("sv1").await.unwrap();
+    assert_eq!(set.boosters.as_ref().unwrap().len(), 2);
+    let koraidon = set.booster("boo_sv1-koraidon").unwrap();
+    assert_eq!(
+        koraidon.logo.as_deref(),
+        Some("https://assets.tcgdex.net/en/sv/sv1/boosters/koraidon")
+    );
+    assert!(set.booster("boo_sv1-unknown").is_none());
+
+    let cards: Vec<_> = set
+        .cards_in_booster(&tcgdex, "boo_sv1-koraidon")
+        .await
+        .into_iter()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let ids: Vec<_> = cards.iter().map(|card| card.id.as_str()).collect();
+    assert_eq!(ids, ["sv1-1", "sv1-2"]);
+
+    // A card that could not be fetched does not prevent getting the others
+    let mock = MockTransport::with_fixtures();
+    mock.route(
+        "*/en/cards/sv1-1",
+        MockResponse::status(StatusCode::NOT_FOUND),
+    );
+    let tcgdex_missing = TCGdex::with_transport(TCGdex::DEFAULT_ENDPOINT, Language::EN, mock);
+    let partial = set
+        .cards_in_booster(&tcgdex_missing, "boo_sv1-koraidon")
+        .await;
+    assert!(matches!(partial[0], Err(Error::NotFound { .. })));
+    assert_eq!(partial[1].as_ref().unwrap().id, "sv1-2");
+    assert_eq!(partial.len(), 2);
+
+    let reverse = &cards[0].variants_detailed.as_ref().unwrap()[1];
+    assert_eq!(reverse.type_, "reverse");
+    assert_eq!(reverse.foil.as_deref(), Some("pokeball"));
+    assert_eq!(
+        reverse.stamp.as_deref(),
+        Some(&["set-logo".to_string()][..])
+    );
+}
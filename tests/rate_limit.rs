@@ -0,0 +1,91 @@
+//! Rate limiter tests against a local stub server
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tcgdex_sdk::{Language, RateLimit, TCGdex};
+use wiremock::matchers::{method, path_regex};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SERIE: &str = r#"{"id": "base", "name": "Base", "sets": []}"#;
+
+async fn server(delay: Duration) -> MockServer {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path_regex("^/en/series/"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(SERIE, "application/json")
+                .set_delay(delay),
+        )
+        .mount(&server)
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn test_requests_per_second() {
+    let server = server(Duration::ZERO).await;
+    let mut tcgdex = TCGdex::with_endpoint(&server.uri(), Language::EN);
+    tcgdex.set_rate_limit(RateLimit {
+        requests_per_second: Some(20.0),
+        burst: 1,
+        max_in_flight: None,
+    });
+
+    let start = Instant::now();
+    let results = fetch_concurrently(tcgdex, 5).await;
+    assert!(results.iter().all(|ok| *ok));
+    // The first request uses the burst, the next four wait 50ms each
+    assert!(start.elapsed() >= Duration::from_millis(190));
+}
+
+#[tokio::test]
+async fn test_tiny_requests_per_second() {
+    let server = server(Duration::ZERO).await;
+    for rate in [1e-300, f64::MIN_POSITIVE, f64::INFINITY, f64::NAN] {
+        let mut tcgdex = TCGdex::with_endpoint(&server.uri(), Language::EN);
+        tcgdex.set_rate_limit(RateLimit {
+            requests_per_second: Some(rate),
+            burst: 1,
+            max_in_flight: None,
+        });
+
+        tcgdex.serie.get("base0").await.unwrap();
+        // The next request waits for a token, if any, without panicking
+        let next = tokio::time::timeout(Duration::from_millis(50), tcgdex.serie.get("base1"));
+        let _ = next.await;
+    }
+}
+
+#[tokio::test]
+async fn test_max_in_flight() {
+    let server = server(Duration::from_millis(100)).await;
+    let mut tcgdex = TCGdex::with_endpoint(&server.uri(), Language::EN);
+    tcgdex.set_rate_limit(RateLimit {
+        max_in_flight: Some(1),
+        ..RateLimit::unlimited()
+    });
+
+    let start = Instant::now();
+    let results = fetch_concurrently(tcgdex, 3).await;
+    assert!(results.iter().all(|ok| *ok));
+    assert!(start.elapsed() >= Duration::from_millis(300));
+}
+
+/// Fetch different series from several concurrent tasks, so requests are not shared
+async fn fetch_concurrently(tcgdex: TCGdex, count: usize) -> Vec<bool> {
+    let tcgdex = Arc::new(tcgdex);
+    let handles: Vec<_> = (0..count)
+        .map(|index| {
+            let tcgdex = Arc::clone(&tcgdex);
+            tokio::spawn(async move { tcgdex.serie.get(&format!("base{index}")).await.is_ok() })
+        })
+        .collect();
+
+    let mut results = Vec::new();
+    for handle in handles {
+        results.push(handle.await.unwrap());
+    }
+    results
+}
@@ -0,0 +1,72 @@
+//! Retry policy tests against a local stub server
+
+use std::time::Duration;
+
+use tcgdex_sdk::{Error, Language, RetryPolicy, TCGdex};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SERIE: &str = r#"{"id": "base", "name": "Base", "sets": []}"#;
+
+fn client(server: &MockServer, max_attempts: u32) -> TCGdex {
+    let mut tcgdex = TCGdex::with_endpoint(&server.uri(), Language::EN);
+    tcgdex.set_retry_policy(RetryPolicy {
+        max_attempts,
+        base_delay: Duration::from_millis(1),
+        jitter: false,
+        ..RetryPolicy::default()
+    });
+    tcgdex
+}
+
+#[tokio::test]
+async fn test_retry_transient_status() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/en/series/base"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/en/series/base"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(SERIE, "application/json"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let serie = client(&server, 3).serie.get("base").await.unwrap();
+    assert_eq!(serie.name, "Base");
+}
+
+#[tokio::test]
+async fn test_retry_gives_up() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/en/series/base"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let err = client(&server, 2).serie.get("base").await.unwrap_err();
+    assert!(matches!(err, Error::Server { status: 500, .. }));
+}
+
+#[tokio::test]
+async fn test_retry_after_too_long() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/en/series/base"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "3600"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let err = client(&server, 3).serie.get("base").await.unwrap_err();
+    assert!(matches!(
+        err,
+        Error::RateLimited { retry_after: Some(delay) } if delay == Duration::from_secs(3600)
+    ));
+}
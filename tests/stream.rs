@@ -0,0 +1,141 @@
+//! Paginated stream tests, served by the mock transport
+
+use futures::{StreamExt, TryStreamExt};
+use reqwest::StatusCode;
+use tcgdex_sdk::testing::{MockResponse, MockTransport};
+use tcgdex_sdk::{Error, Language, Query, RetryPolicy, StreamConfig, TCGdex};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const PAGE_1: &str = r#"[{"id": "base", "name": "Base"}, {"id": "gym", "name": "Gym"}]"#;
+const PAGE_2: &str =
+    r#"[{"id": "neo", "name": "Neo"}, {"id": "lc", "name": "Legendary Collection"}]"#;
+const PAGE_3: &str = r#"[{"id": "ecard", "name": "E-Card"}]"#;
+
+fn page_route(page: u32) -> String {
+    format!("*/en/series?pagination%3Apage={page}&pagination%3AitemsPerPage=2")
+}
+
+fn client(mock: MockTransport) -> TCGdex {
+    let mut tcgdex = TCGdex::with_transport(TCGdex::DEFAULT_ENDPOINT, Language::EN, mock);
+    tcgdex.set_retry_policy(RetryPolicy::none());
+    tcgdex
+}
+
+fn config(prefetch: bool) -> StreamConfig {
+    StreamConfig {
+        page_size: 2,
+        prefetch,
+    }
+}
+
+#[tokio::test]
+async fn test_stream_stops_on_short_page() {
+    for prefetch in [false, true] {
+        let mock = MockTransport::new();
+        mock.json(page_route(1), PAGE_1)
+            .json(page_route(2), PAGE_2)
+            .json(page_route(3), PAGE_3);
+        let tcgdex = client(mock);
+
+        let series: Vec<_> = tcgdex
+            .serie
+            .stream_with(None, config(prefetch))
+            .try_collect()
+            .await
+            .unwrap();
+
+        let ids: Vec<_> = series.iter().map(|serie| serie.id.as_str()).collect();
+        assert_eq!(ids, ["base", "gym", "neo", "lc", "ecard"]);
+    }
+}
+
+#[tokio::test]
+async fn test_stream_stops_on_empty_page() {
+    let mock = MockTransport::new();
+    mock.json(page_route(1), PAGE_1).json(page_route(2), "[]");
+    let tcgdex = client(mock);
+
+    let count = tcgdex.serie.stream_with(None, config(true)).count().await;
+    assert_eq!(count, 2);
+}
+
+#[tokio::test]
+async fn test_stream_is_lazy() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/en/series"))
+        .and(query_param("pagination:page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(PAGE_1, "application/json"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/en/series"))
+        .and(query_param("pagination:page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(PAGE_2, "application/json"))
+        .expect(0)
+        .mount(&server)
+        .await;
+    let tcgdex = TCGdex::with_endpoint(&server.uri(), Language::EN);
+
+    let mut stream = Box::pin(tcgdex.serie.stream_with(None, config(false)));
+    let first = stream.next().await.unwrap().unwrap();
+    let second = stream.next().await.unwrap().unwrap();
+    assert_eq!((first.id.as_str(), second.id.as_str()), ("base", "gym"));
+}
+
+#[tokio::test]
+async fn test_stream_keeps_query() {
+    let mock = MockTransport::new();
+    mock.json(
+        "*/en/series?name=Base&pagination%3Apage=1&pagination%3AitemsPerPage=2",
+        r#"[{"id": "base", "name": "Base"}]"#,
+    );
+    let tcgdex = client(mock);
+
+    let mut query = Query::new();
+    query.contains("name", "Base");
+    let series: Vec<_> = tcgdex
+        .serie
+        .stream_with(Some(&query), config(true))
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(series.len(), 1);
+}
+
+#[tokio::test]
+async fn test_stream_ignores_query_pagination() {
+    let mock = MockTransport::new();
+    mock.json(page_route(1), PAGE_1)
+        .json(page_route(2), PAGE_2)
+        .json(page_route(3), PAGE_3);
+    let tcgdex = client(mock);
+
+    // The pages of the stream replace the ones of the query
+    let mut query = Query::new();
+    query.paginate(1, 2);
+    let series: Vec<_> = tcgdex
+        .serie
+        .stream_with(Some(&query), config(false))
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(series.len(), 5);
+}
+
+#[tokio::test]
+async fn test_stream_ends_after_error() {
+    let mock = MockTransport::new();
+    mock.json(page_route(1), PAGE_1).route(
+        page_route(2),
+        MockResponse::status(StatusCode::INTERNAL_SERVER_ERROR),
+    );
+    let tcgdex = client(mock);
+
+    let results: Vec<_> = tcgdex.serie.stream_with(None, config(true)).collect().await;
+    assert_eq!(results.len(), 3);
+    assert!(results[..2].iter().all(Result::is_ok));
+    assert!(matches!(results[2], Err(Error::Server { status: 500, .. })));
+}
@@ -0,0 +1,99 @@
+//! Tracing events emitted when the `tracing` feature is enabled
+
+#![cfg(feature = "tracing")]
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use tcgdex_sdk::testing::{MockResponse, MockTransport};
+use tcgdex_sdk::{CacheConfig, Extension, Language, Quality, TCGdex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// Subscriber collecting the fields of every event as `name=value` strings
+#[derive(Clone, Default)]
+struct Collector {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+/// Visitor formatting the fields of an event
+struct Fields(String);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.push_str(&format!(" {}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for Collector {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields(String::new());
+        event.record(&mut fields);
+        self.events.lock().unwrap().push(fields.0);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[tokio::test]
+async fn test_response_events() {
+    let collector = Collector::default();
+    let _guard = tracing::subscriber::set_default(collector.clone());
+
+    let mut tcgdex = TCGdex::with_transport(
+        TCGdex::DEFAULT_ENDPOINT,
+        Language::EN,
+        MockTransport::with_fixtures(),
+    );
+    tcgdex.set_cache(Some(CacheConfig::default()));
+
+    tcgdex.card.get("base1-4").await.unwrap();
+    tcgdex.card.get("base1-4").await.unwrap();
+
+    let events = collector.events.lock().unwrap();
+    assert_eq!(events.len(), 2);
+    assert!(events[0].contains("status=200"));
+    assert!(events[0].contains("cache=\"miss\""));
+    assert!(events[0].contains("bytes="));
+    assert!(events[0].contains("latency_ms="));
+    assert!(events[1].contains("cache=\"memory\""));
+}
+
+#[tokio::test]
+async fn test_image_download_events() {
+    let collector = Collector::default();
+    let _guard = tracing::subscriber::set_default(collector.clone());
+
+    let mock = MockTransport::with_fixtures();
+    mock.route(
+        "*/en/base/base1/4/high.png",
+        MockResponse::status(reqwest::StatusCode::OK).with_body(&b"\x89PNG"[..]),
+    );
+    let tcgdex = TCGdex::with_transport(TCGdex::DEFAULT_ENDPOINT, Language::EN, mock);
+
+    let card = tcgdex.card.get("base1-4").await.unwrap();
+    card.get_image(&tcgdex, Quality::HIGH, Extension::PNG)
+        .await
+        .unwrap();
+
+    let events = collector.events.lock().unwrap();
+    assert_eq!(events.len(), 2);
+    assert!(events[1].contains("high.png"));
+    assert!(events[1].contains("status=200"));
+    assert!(events[1].contains("bytes=4"));
+}
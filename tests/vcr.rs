@@ -0,0 +1,46 @@
+//! Record and replay tests, recording from the mock transport
+
+use tcgdex_sdk::testing::{MockResponse, MockTransport};
+use tcgdex_sdk::{Error, Extension, Language, Quality, TCGdex};
+
+const IMAGE: &[u8] = b"\x89PNG\r\n\x1a\n\xff\x00";
+
+#[tokio::test]
+async fn test_record_and_replay() {
+    let dir = tempfile::tempdir().unwrap();
+    let cassette = dir.path().join("cassette.json");
+
+    let mock = MockTransport::with_fixtures();
+    mock.route(
+        "*/high.png",
+        MockResponse::status(reqwest::StatusCode::OK).with_body(IMAGE),
+    );
+    let mut tcgdex = TCGdex::with_transport(TCGdex::DEFAULT_ENDPOINT, Language::EN, mock);
+    tcgdex.record(&cassette);
+
+    let card = tcgdex.card.get("base1-4").await.unwrap();
+    let image = card
+        .get_image(&tcgdex, Quality::HIGH, Extension::PNG)
+        .await
+        .unwrap();
+    assert_eq!(image.as_deref(), Some(IMAGE));
+
+    // Replay without any route: everything must come from the cassette
+    let mut tcgdex =
+        TCGdex::with_transport(TCGdex::DEFAULT_ENDPOINT, Language::EN, MockTransport::new());
+    tcgdex.replay(&cassette).unwrap();
+
+    let replayed = tcgdex.card.get("base1-4").await.unwrap();
+    assert_eq!(replayed.name, card.name);
+    let image = replayed
+        .get_image(&tcgdex, Quality::HIGH, Extension::PNG)
+        .await
+        .unwrap();
+    assert_eq!(image.as_deref(), Some(IMAGE));
+
+    // Interactions can be served again
+    assert!(tcgdex.card.get("base1-4").await.is_ok());
+
+    let err = tcgdex.set.get("base1").await.unwrap_err();
+    assert!(matches!(err, Error::NotRecorded(url) if url.ends_with("/en/sets/base1")));
+}